@@ -0,0 +1,98 @@
+//! Extracts the source of every example function in `src/models` so the
+//! showcase can display it next to the live rendering.
+
+use std::{env, fs, path::Path};
+
+fn main() {
+    let models_dir = Path::new("src/models");
+    println!("cargo:rerun-if-changed={}", models_dir.display());
+
+    let mut paths = fs::read_dir(models_dir)
+        .expect("failed to read src/models")
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with("_component.rs"))
+        })
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    let mut output = String::from("static EXAMPLE_SOURCES: &[(&str, &str, &str)] = &[\n");
+    for path in paths {
+        println!("cargo:rerun-if-changed={}", path.display());
+        let source = fs::read_to_string(&path).expect("failed to read model file");
+        for (view, function, body) in extract_functions(&source) {
+            output.push_str(&format!("    ({:?}, {:?}, {:?}),\n", view, function, body));
+        }
+    }
+    output.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is not set");
+    fs::write(Path::new(&out_dir).join("example_sources.rs"), output)
+        .expect("failed to write example sources");
+}
+
+/// Collect `(type, fn name, fn source)` for every function of the inherent
+/// `impl Type { ... }` blocks, which is where the example code lives.
+fn extract_functions(source: &str) -> Vec<(String, String, String)> {
+    let mut functions = Vec::new();
+    let mut current_impl: Option<String> = None;
+    let mut current_fn: Option<(String, Vec<&str>)> = None;
+
+    for line in source.lines() {
+        if let Some((name, lines)) = current_fn.as_mut() {
+            lines.push(line);
+            if line == "    }" {
+                let body = lines
+                    .iter()
+                    .map(|line| line.strip_prefix("    ").unwrap_or(line))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let view = current_impl.clone().unwrap_or_default();
+                functions.push((view, name.clone(), body));
+                current_fn = None;
+            }
+            continue;
+        }
+
+        if let Some(rest) = line.strip_prefix("impl ") {
+            current_impl = rest
+                .strip_suffix(" {")
+                .filter(|name| !name.contains(' ') && !name.contains('<'))
+                .map(str::to_string);
+            continue;
+        }
+
+        if line == "}" {
+            current_impl = None;
+            continue;
+        }
+
+        if current_impl.is_none() {
+            continue;
+        }
+
+        let signature = line.trim_start();
+        let indent = line.len() - signature.len();
+        if indent != 4 {
+            continue;
+        }
+        let signature = signature.strip_prefix("pub ").unwrap_or(signature);
+        if let Some(rest) = signature.strip_prefix("fn ") {
+            let name = rest
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .next()
+                .unwrap_or_default()
+                .to_string();
+            if line.ends_with('}') && line.matches('{').count() == line.matches('}').count() {
+                let view = current_impl.clone().unwrap_or_default();
+                functions.push((view, name, signature.to_string()));
+            } else {
+                current_fn = Some((name, vec![line]));
+            }
+        }
+    }
+
+    functions
+}
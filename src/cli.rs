@@ -0,0 +1,263 @@
+use anyhow::{Context as _, anyhow, bail};
+use gpui::{Pixels, Size, px, size};
+use strum::IntoEnumIterator;
+
+use crate::models::{Components, REGISTRY};
+use crate::preferences::ThemePreference;
+
+pub const USAGE: &str = "\
+Usage: gpui-component-sample [OPTIONS]
+
+Options:
+      --component <NAME>  Open the page of a component, e.g. DatePicker
+      --example <TITLE>   Scroll to an example, by title or id, e.g. \"Date Range Presets\"
+      --theme <MODE>      Theme mode for this launch: light, dark or system
+      --size <WxH>        Window size, e.g. 1400x900
+      --list              Print every component and its examples, then exit
+      --reset-session     Forget the last session and start from the welcome page
+  -h, --help              Print this help";
+
+/// Command line options of the gallery
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Cli {
+    pub component: Option<Components>,
+    /// Id of the example to scroll to, resolved from `--example`
+    pub example: Option<&'static str>,
+    pub theme: Option<ThemePreference>,
+    pub size: Option<Size<Pixels>>,
+    pub list: bool,
+    pub reset_session: bool,
+    pub help: bool,
+}
+
+impl Cli {
+    /// Parse the arguments following the program name
+    pub fn parse(args: impl IntoIterator<Item = String>) -> anyhow::Result<Self> {
+        let mut cli = Self::default();
+        let mut example = None;
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            // Accept both `--flag value` and `--flag=value`
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            let mut value = || {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .with_context(|| format!("{} expects a value", flag))
+            };
+
+            match flag.as_str() {
+                "--component" => {
+                    let name = value()?;
+                    let component = name.parse::<Components>().map_err(|_| {
+                        anyhow!(
+                            "unknown component `{}`, expected one of: {}",
+                            name,
+                            Components::iter()
+                                .map(|component| component.to_string())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )
+                    })?;
+                    cli.component = Some(component);
+                }
+                "--example" => example = Some(value()?),
+                "--theme" => {
+                    let mode = value()?;
+                    let theme = mode.parse().map_err(|_| {
+                        anyhow!("unknown theme `{}`, expected light, dark or system", mode)
+                    })?;
+                    cli.theme = Some(theme);
+                }
+                "--size" => cli.size = Some(parse_size(&value()?)?),
+                "--list" => cli.list = true,
+                "--reset-session" => cli.reset_session = true,
+                "-h" | "--help" => cli.help = true,
+                _ => bail!("unexpected argument `{}`", flag),
+            }
+        }
+
+        if let Some(example) = example {
+            let (component, id) = find_example(cli.component, &example)?;
+            cli.component = Some(component);
+            cli.example = Some(id);
+        }
+
+        Ok(cli)
+    }
+}
+
+/// Parse a `WIDTHxHEIGHT` window size
+fn parse_size(value: &str) -> anyhow::Result<Size<Pixels>> {
+    let (width, height) = value
+        .split_once(['x', 'X'])
+        .with_context(|| format!("invalid size `{}`, expected WIDTHxHEIGHT", value))?;
+    let parse = |side: &str| {
+        side.trim()
+            .parse::<f32>()
+            .ok()
+            .filter(|side| side.is_finite() && *side > 0.)
+            .with_context(|| format!("invalid size `{}`, expected WIDTHxHEIGHT", value))
+    };
+
+    Ok(size(px(parse(width)?), px(parse(height)?)))
+}
+
+/// Find an example by title or id, in `component` if given, otherwise in any component
+fn find_example(
+    component: Option<Components>,
+    example: &str,
+) -> anyhow::Result<(Components, &'static str)> {
+    let matches = REGISTRY
+        .iter()
+        .filter(|entry| component.is_none_or(|component| component == entry.component))
+        .flat_map(|entry| {
+            entry
+                .examples()
+                .into_iter()
+                .filter(|info| info.id == example || info.title.eq_ignore_ascii_case(example))
+                .map(move |info| (entry.component, info.id))
+        })
+        .collect::<Vec<_>>();
+
+    match matches.as_slice() {
+        [found] => Ok(*found),
+        [] => match component {
+            Some(component) => bail!("{} has no example `{}`", component.name(), example),
+            None => bail!("no example `{}`", example),
+        },
+        _ => bail!(
+            "several components have an example `{}`, pick one with --component: {}",
+            example,
+            matches
+                .iter()
+                .map(|(component, _)| component.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Print every component with the id and title of its examples, for `--list`
+pub fn print_list() {
+    for entry in REGISTRY {
+        println!("{} ({})", entry.component, entry.category);
+        for example in entry.examples() {
+            let status = if example.implemented {
+                ""
+            } else {
+                " (not implemented yet)"
+            };
+            println!("    {:<40} {}{}", example.id, example.title, status);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> anyhow::Result<Cli> {
+        Cli::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_accepts_separate_and_inline_values() {
+        let expected = Cli {
+            component: Some(Components::DatePicker),
+            theme: Some(ThemePreference::Light),
+            size: Some(size(px(1400.), px(900.))),
+            ..Default::default()
+        };
+
+        let separate = parse(&[
+            "--component",
+            "DatePicker",
+            "--theme",
+            "light",
+            "--size",
+            "1400x900",
+        ]);
+        let inline = parse(&["--component=DatePicker", "--theme=light", "--size=1400x900"]);
+        assert_eq!(separate.unwrap(), expected);
+        assert_eq!(inline.unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_flags() {
+        let cli = parse(&["--list", "--reset-session", "-h"]).unwrap();
+        assert!(cli.list && cli.reset_session && cli.help);
+        assert_eq!(parse(&[]).unwrap(), Cli::default());
+    }
+
+    #[test]
+    fn parse_resolves_the_example_component() {
+        let cli = parse(&["--example", "Date Range Picker"]).unwrap();
+        assert_eq!(cli.component, Some(Components::DatePicker));
+        assert_eq!(cli.example, Some("date_range_picker"));
+    }
+
+    #[test]
+    fn parse_rejects_unknown_arguments_and_values() {
+        assert!(parse(&["--verbose"]).is_err());
+        assert!(parse(&["DatePicker"]).is_err());
+        assert!(parse(&["--component", "Nope"]).is_err());
+        assert!(parse(&["--theme=blue"]).is_err());
+    }
+
+    #[test]
+    fn parse_rejects_missing_values() {
+        for flag in ["--component", "--example", "--theme", "--size"] {
+            let err = parse(&[flag]).unwrap_err();
+            assert_eq!(err.to_string(), format!("{} expects a value", flag));
+        }
+    }
+
+    #[test]
+    fn parse_size_values() {
+        assert_eq!(parse_size("800x600").unwrap(), size(px(800.), px(600.)));
+        assert_eq!(parse_size("800X600").unwrap(), size(px(800.), px(600.)));
+        assert_eq!(parse_size(" 800 x 600 ").unwrap(), size(px(800.), px(600.)));
+        for invalid in [
+            "800",
+            "800x",
+            "x600",
+            "0x600",
+            "-800x600",
+            "infxinf",
+            "800xinf",
+            "NaNx600",
+            "wide x tall",
+        ] {
+            assert!(
+                parse_size(invalid).is_err(),
+                "{} should be rejected",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn find_example_by_id_or_title() {
+        let found = (Components::DatePicker, "basic_date_picker");
+        assert_eq!(find_example(None, "basic_date_picker").unwrap(), found);
+        assert_eq!(find_example(None, "basic date picker").unwrap(), found);
+        assert_eq!(
+            find_example(Some(Components::DatePicker), "Basic Date Picker").unwrap(),
+            found
+        );
+    }
+
+    #[test]
+    fn find_example_errors() {
+        assert!(find_example(None, "no_such_example").is_err());
+        assert!(find_example(Some(Components::Button), "basic_date_picker").is_err());
+        // Several pages have a "Disabled State" example
+        assert!(find_example(None, "disabled_state").is_err());
+        assert!(find_example(Some(Components::Checkbox), "disabled_state").is_ok());
+    }
+}
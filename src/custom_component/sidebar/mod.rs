@@ -3,13 +3,15 @@ use gpui_component::button::{Button, ButtonVariants};
 use gpui_component::{Icon, IconName, Side, Sizable};
 use std::rc::Rc;
 
+type ClickHandler = Rc<dyn Fn(&ClickEvent, &mut Window, &mut App)>;
+
 /// A custom SidebarToggleButton with configurable icon size
 #[derive(IntoElement)]
 pub struct CustomSidebarToggleButton {
     btn: Button,
     collapsed: bool,
     side: Side,
-    on_click: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App)>>,
+    on_click: Option<ClickHandler>,
 }
 
 impl CustomSidebarToggleButton {
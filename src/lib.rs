@@ -1,6 +1,42 @@
+/// Command line options
+pub mod cli;
 /// Custom components for gpui-component-sample
 pub mod custom_component;
 pub use custom_component::*;
 /// Models and views for gpui-component-sample
 pub mod models;
+/// User preferences persisted across launches
+pub mod preferences;
+/// Session state restored on launch
+pub mod session;
+/// Custom theme files loaded from the `themes` directory
+pub mod themes;
 pub mod views;
+
+use gpui::App;
+use gpui_component::highlighter::{LanguageConfig, LanguageRegistry};
+
+/// Initialize the sample application, call after `gpui_component::init`
+pub fn init(cx: &mut App) {
+    cx.set_global(preferences::Preferences::load());
+    cx.set_global(session::Session::load());
+    themes::init(cx);
+    models::init(cx);
+    init_languages();
+}
+
+/// Register the languages of the code editors, the example sources are
+/// shown with Rust syntax highlighting
+pub fn init_languages() {
+    LanguageRegistry::singleton().register(
+        "rust",
+        &LanguageConfig::new(
+            "rust",
+            tree_sitter_rust::LANGUAGE.into(),
+            vec![],
+            tree_sitter_rust::HIGHLIGHTS_QUERY,
+            tree_sitter_rust::INJECTIONS_QUERY,
+            "",
+        ),
+    );
+}
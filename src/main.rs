@@ -2,39 +2,95 @@ use gpui::*;
 use gpui_component::*;
 use gpui_component_assets::Assets;
 
-use gpui_component_sample::views::MyApp;
+use gpui_component_sample::cli::{self, Cli};
+use gpui_component_sample::preferences::Preferences;
+use gpui_component_sample::session::Session;
+use gpui_component_sample::themes;
+use gpui_component_sample::views::{MyApp, ToggleCommandPalette};
 
 actions!(my_app, [Quit]);
 
 fn main() {
+    let cli = match Cli::parse(std::env::args().skip(1)) {
+        Ok(cli) => cli,
+        Err(err) => {
+            eprintln!("error: {:#}\n\n{}", err, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+    if cli.help {
+        println!("{}", cli::USAGE);
+        return;
+    }
+    if cli.list {
+        cli::print_list();
+        return;
+    }
+
+    // Start from the welcome page with the default window, e.g. if the saved
+    // session keeps the window off-screen
+    if cli.reset_session
+        && let Err(err) = Session::reset()
+    {
+        eprintln!("Failed to reset the session: {:#}", err);
+    }
+
     let app = Application::new().with_assets(Assets);
 
     app.run(move |cx| {
         gpui_component::init(cx);
+        gpui_component_sample::init(cx);
 
         cx.bind_keys([
             #[cfg(target_os = "macos")]
             KeyBinding::new("cmd-q", Quit, None),
+            #[cfg(target_os = "macos")]
+            KeyBinding::new("cmd-k", ToggleCommandPalette, None),
+            #[cfg(not(target_os = "macos"))]
+            KeyBinding::new("ctrl-k", ToggleCommandPalette, None),
         ]);
 
         cx.on_action(|_: &Quit, cx: &mut App| {
             cx.quit();
         });
 
+        // The theme given on the command line is only used for this launch,
+        // it is not saved with the preferences
+        cx.global_mut::<Preferences>().launch_theme = cli.theme;
+
         let window_size = size(px(1150.), px(700.));
+        let window_bounds = match cli.size {
+            Some(size) => WindowBounds::centered(size, cx),
+            None => Session::global(cx)
+                .window_bounds()
+                .unwrap_or_else(|| WindowBounds::centered(window_size, cx)),
+        };
         let window_options = WindowOptions {
             titlebar: Some(TitleBar::title_bar_options()),
-            window_bounds: Some(WindowBounds::centered(window_size, cx)),
-            window_min_size: Some(window_size),
+            window_bounds: Some(window_bounds),
+            // Allow a smaller window when one is asked for explicitly
+            window_min_size: Some(size(
+                window_size.width.min(window_bounds.get_bounds().size.width),
+                window_size
+                    .height
+                    .min(window_bounds.get_bounds().size.height),
+            )),
             ..Default::default()
         };
 
         cx.spawn(async move |cx| {
             cx.open_window(window_options, |window, cx| {
                 window.activate_window();
-                Theme::change(ThemeMode::Dark, Some(window), cx);
+                themes::apply(Some(window), cx);
 
-                let view = cx.new(|cx| MyApp::new(cx));
+                let view = cx.new(|cx| MyApp::new(window, cx));
+                if let Some(component) = cli.component {
+                    let main_page = view.read(cx).main_page.clone();
+                    main_page.update(cx, |page, cx| match cli.example {
+                        Some(example) => page.show_example(component, example, window, cx),
+                        None => page.show_component(component, window, cx),
+                    });
+                }
                 cx.new(|cx| Root::new(view, window, cx))
             })?;
 
@@ -4,7 +4,7 @@ use gpui::*;
 use gpui_component::accordion::Accordion;
 use gpui_component::*;
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
 
 pub struct AccordionComponentView {
     /// A set to track which accordion items are open by their unique key
@@ -12,52 +12,48 @@ pub struct AccordionComponentView {
 }
 
 impl AccordionComponentView {
-    pub fn new() -> Self {
-        Self {
-            open_items: HashSet::new(),
-        }
-    }
-
     fn is_open(&self, key: &str) -> bool {
         self.open_items.contains(key)
     }
 }
 
 impl ComponentMeta for AccordionComponentView {
+    const NAME: &'static str = "Accordion";
+    const CATEGORY: ComponentCategory = ComponentCategory::Layout;
     const DESCRIPTION: &'static str = "An accordion component that allows users to show and hide sections of content. \nIt uses collapse functionality internally to create collapsible panels.";
     const LINK: &'static str =
         "https://longbridge.github.io/gpui-component/docs/components/accordion";
-}
 
-impl Render for AccordionComponentView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        let entity = _cx.entity().clone();
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self {
+            open_items: HashSet::new(),
+        }
+    }
 
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Basic Accordion"))
-            .child(self.basic_accordion(entity.clone()))
-            .child(subtitle("Multiple Open Items"))
-            .child(self.multiple_open_items_accordion(entity.clone()))
-            .child(subtitle("With Borders"))
-            .child(self.with_borders_accordion(entity.clone()))
-            .child(subtitle("Different Sizes"))
-            .child(self.different_sizes_accordion(entity.clone()))
-            .child(subtitle("Handle Toggle Events"))
-            .child(self.handle_toggle_events_accordion(entity.clone()))
-            .child(subtitle("Disabled State"))
-            .child(self.disabled_state_accordion(entity.clone()))
-            .child(subtitle("With Custom Icons"))
-            .child(self.with_custom_icons_accordion(entity.clone()))
-            .child(subtitle("Nested Accordions"))
-            .child(self.nested_accordions_accordion(entity.clone()))
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_accordion", "Basic Accordion")
+                .render(|this, _, cx| this.basic_accordion(cx.entity())),
+            Self::example("multiple_open_items_accordion", "Multiple Open Items")
+                .render(|this, _, cx| this.multiple_open_items_accordion(cx.entity())),
+            Self::example("with_borders_accordion", "With Borders")
+                .render(|this, _, cx| this.with_borders_accordion(cx.entity())),
+            Self::example("different_sizes_accordion", "Different Sizes")
+                .render(|this, _, cx| this.different_sizes_accordion(cx.entity())),
+            Self::example("handle_toggle_events_accordion", "Handle Toggle Events")
+                .render(|this, _, cx| this.handle_toggle_events_accordion(cx.entity())),
+            Self::example("disabled_state_accordion", "Disabled State")
+                .render(|this, _, cx| this.disabled_state_accordion(cx.entity())),
+            Self::example("with_custom_icons_accordion", "With Custom Icons")
+                .render(|this, _, cx| this.with_custom_icons_accordion(cx.entity())),
+            Self::example("nested_accordions_accordion", "Nested Accordions")
+                .render(|this, _, cx| this.nested_accordions_accordion(cx.entity())),
+        ]
     }
 }
 
 impl AccordionComponentView {
-    /// Example code for the Accordion component
+    // Example code for the Accordion component
 
     fn basic_accordion(&self, entity: Entity<Self>) -> AnyElement {
         let e = entity.clone();
@@ -3,54 +3,58 @@ use gpui_component::alert::Alert;
 // use gpui_component::text::markdown;
 use gpui_component::*;
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
 
 pub struct AlertComponentView;
 
 impl ComponentMeta for AlertComponentView {
+    const NAME: &'static str = "Alert";
+    const CATEGORY: ComponentCategory = ComponentCategory::Feedback;
     const DESCRIPTION: &'static str = "A versatile alert component for displaying important messages to users. \nSupports multiple variants (info, success, warning, error), custom icons, optional titles, \nclosable functionality, and banner mode.";
     const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/alert";
-}
 
-impl Render for AlertComponentView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Basic Alert"))
-            .child(self.basic_alert())
-            .child(subtitle("Alert with Title"))
-            .child(self.alert_with_title())
-            .child(subtitle("Alert Variants"))
-            .child(self.alert_variants())
-            .child(subtitle("Alert Sizes"))
-            .child(self.alert_sizes())
-            .child(subtitle("Closable Alerts"))
-            .child(self.closable_alerts())
-            .child(subtitle("Banner Mode"))
-            .child(self.banner_mode())
-            .child(subtitle("Custom Icons"))
-            .child(self.custom_icons())
-            .child(subtitle("With Markdown Content"))
-            .child(self.with_markdown_content())
-            .child(subtitle("Conditional Visibility"))
-            .child(self.conditional_visibility())
-            .child(subtitle("Form Validation Errors"))
-            .child(self.form_validation_errors())
-            .child(subtitle("Success Notification"))
-            .child(self.success_notification())
-            .child(subtitle("System Status Banner"))
-            .child(self.system_status_banner())
-            .child(subtitle("Interactive Alert with Custom Action"))
-            .child(self.interactive_alert_with_custom_action())
-            .child(subtitle("Multi-line Content with Formatting"))
-            .child(self.multi_line_content_with_formatting())
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_alert", "Basic Alert").render(|this, _, _| this.basic_alert()),
+            Self::example("alert_with_title", "Alert with Title")
+                .render(|this, _, _| this.alert_with_title()),
+            Self::example("alert_variants", "Alert Variants")
+                .render(|this, _, _| this.alert_variants()),
+            Self::example("alert_sizes", "Alert Sizes").render(|this, _, _| this.alert_sizes()),
+            Self::example("closable_alerts", "Closable Alerts")
+                .render(|this, _, _| this.closable_alerts()),
+            Self::example("banner_mode", "Banner Mode").render(|this, _, _| this.banner_mode()),
+            Self::example("custom_icons", "Custom Icons").render(|this, _, _| this.custom_icons()),
+            Self::example("with_markdown_content", "With Markdown Content")
+                .render(|this, _, _| this.with_markdown_content()),
+            Self::example("conditional_visibility", "Conditional Visibility")
+                .render(|this, _, _| this.conditional_visibility()),
+            Self::example("form_validation_errors", "Form Validation Errors")
+                .render(|this, _, _| this.form_validation_errors()),
+            Self::example("success_notification", "Success Notification")
+                .render(|this, _, _| this.success_notification()),
+            Self::example("system_status_banner", "System Status Banner")
+                .render(|this, _, _| this.system_status_banner()),
+            Self::example(
+                "interactive_alert_with_custom_action",
+                "Interactive Alert with Custom Action",
+            )
+            .render(|this, _, _| this.interactive_alert_with_custom_action()),
+            Self::example(
+                "multi_line_content_with_formatting",
+                "Multi-line Content with Formatting",
+            )
+            .render(|this, _, _| this.multi_line_content_with_formatting()),
+        ]
     }
 }
 
 impl AlertComponentView {
-    /// Example code for the Alert component
+    // Example code for the Alert component
 
     fn basic_alert(&self) -> AnyElement {
         Alert::new("alert-id", "This is a basic alert message.").into_any_element()
@@ -2,54 +2,51 @@ use gpui::*;
 use gpui_component::avatar::{Avatar, AvatarGroup};
 use gpui_component::*;
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
 
 pub struct AvatarComponentView;
 
 impl ComponentMeta for AvatarComponentView {
+    const NAME: &'static str = "Avatar";
+    const CATEGORY: ComponentCategory = ComponentCategory::General;
     const DESCRIPTION: &'static str = "The Avatar component displays user profile images with intelligent fallbacks. \nWhen no image is provided, it shows user initials or a placeholder icon.";
     const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/avatar";
-}
 
-impl Render for AvatarComponentView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Basic Avatar"))
-            .child(self.basic_avatar())
-            .child(subtitle("Avatar with Fallback Text"))
-            .child(self.avatar_with_fallback_text())
-            .child(subtitle("Avatar Placeholder"))
-            .child(self.avatar_placeholder())
-            .child(subtitle("Avatar Sizes"))
-            .child(self.avatar_sizes())
-            .child(subtitle("Custom Styling"))
-            .child(self.custom_styling(_cx))
-            .child(subtitle("Basic Group"))
-            .child(self.basic_group())
-            .child(subtitle("Group with Limit"))
-            .child(self.group_with_limit())
-            .child(subtitle("Group with Ellipsis"))
-            .child(self.group_with_ellipsis())
-            .child(subtitle("Group Sizes"))
-            .child(self.group_sizes())
-            .child(subtitle("Adding Multiple Avatars"))
-            .child(self.adding_multiple_avatars())
-            .child(subtitle("Team Display"))
-            .child(self.team_display())
-            .child(subtitle("User Profile Header"))
-            .child(self.user_profile_header(_cx))
-            .child(subtitle("Anonymous User"))
-            .child(self.anonymous_user())
-            .child(subtitle("Avatar with Custom Colors"))
-            .child(self.avatar_with_custom_colors())
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_avatar", "Basic Avatar").render(|this, _, _| this.basic_avatar()),
+            Self::example("avatar_with_fallback_text", "Avatar with Fallback Text")
+                .render(|this, _, _| this.avatar_with_fallback_text()),
+            Self::example("avatar_placeholder", "Avatar Placeholder")
+                .render(|this, _, _| this.avatar_placeholder()),
+            Self::example("avatar_sizes", "Avatar Sizes").render(|this, _, _| this.avatar_sizes()),
+            Self::example("custom_styling", "Custom Styling")
+                .render(|this, _, cx| this.custom_styling(cx)),
+            Self::example("basic_group", "Basic Group").render(|this, _, _| this.basic_group()),
+            Self::example("group_with_limit", "Group with Limit")
+                .render(|this, _, _| this.group_with_limit()),
+            Self::example("group_with_ellipsis", "Group with Ellipsis")
+                .render(|this, _, _| this.group_with_ellipsis()),
+            Self::example("group_sizes", "Group Sizes").render(|this, _, _| this.group_sizes()),
+            Self::example("adding_multiple_avatars", "Adding Multiple Avatars")
+                .render(|this, _, _| this.adding_multiple_avatars()),
+            Self::example("team_display", "Team Display").render(|this, _, _| this.team_display()),
+            Self::example("user_profile_header", "User Profile Header")
+                .render(|this, _, cx| this.user_profile_header(cx)),
+            Self::example("anonymous_user", "Anonymous User")
+                .render(|this, _, _| this.anonymous_user()),
+            Self::example("avatar_with_custom_colors", "Avatar with Custom Colors")
+                .render(|this, _, _| this.avatar_with_custom_colors()),
+        ]
     }
 }
 
 impl AvatarComponentView {
-    /// Example code for the Avatar component
+    // Example code for the Avatar component
 
     fn basic_avatar(&self) -> AnyElement {
         Avatar::new()
@@ -4,48 +4,48 @@ use gpui_component::badge::Badge;
 use gpui_component::link::Link;
 use gpui_component::*;
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
 
 pub struct BadgeComponentView;
 
 impl ComponentMeta for BadgeComponentView {
+    const NAME: &'static str = "Badge";
+    const CATEGORY: ComponentCategory = ComponentCategory::General;
     const DESCRIPTION: &'static str = "A versatile badge component that can display counts, dots, or icons on elements. \nPerfect for indicating notifications, status, or other contextual information.";
     const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/badge";
-}
 
-impl Render for BadgeComponentView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Badge with Count"))
-            .child(self.badge_with_count())
-            .child(subtitle("Variants"))
-            .child(self.variants())
-            .child(subtitle("Badge Sizes"))
-            .child(self.badge_sizes())
-            .child(subtitle("Badge Colors"))
-            .child(self.badge_colors(_cx))
-            .child(subtitle("Badge on Icons"))
-            .child(self.badge_on_icons())
-            .child(subtitle("Badge on Avatars"))
-            .child(self.badge_on_avatars(_cx))
-            .child(subtitle("Complex Nested Badges"))
-            .child(self.complex_nested_badges(_cx))
-            .child(subtitle("Notification Indicators"))
-            .child(self.notification_indicators(_cx))
-            .child(subtitle("Status Indicators"))
-            .child(self.status_indicators(_cx))
-            .child(subtitle("Different Badge Positions"))
-            .child(self.different_badge_positions())
-            .child(subtitle("Count Formatting"))
-            .child(self.count_formatting())
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("badge_with_count", "Badge with Count")
+                .render(|this, _, _| this.badge_with_count()),
+            Self::example("variants", "Variants").render(|this, _, _| this.variants()),
+            Self::example("badge_sizes", "Badge Sizes").render(|this, _, _| this.badge_sizes()),
+            Self::example("badge_colors", "Badge Colors")
+                .render(|this, _, cx| this.badge_colors(cx)),
+            Self::example("badge_on_icons", "Badge on Icons")
+                .render(|this, _, _| this.badge_on_icons()),
+            Self::example("badge_on_avatars", "Badge on Avatars")
+                .render(|this, _, cx| this.badge_on_avatars(cx)),
+            Self::example("complex_nested_badges", "Complex Nested Badges")
+                .render(|this, _, cx| this.complex_nested_badges(cx)),
+            Self::example("notification_indicators", "Notification Indicators")
+                .render(|this, _, cx| this.notification_indicators(cx)),
+            Self::example("status_indicators", "Status Indicators")
+                .render(|this, _, cx| this.status_indicators(cx)),
+            Self::example("different_badge_positions", "Different Badge Positions")
+                .render(|this, _, _| this.different_badge_positions()),
+            Self::example("count_formatting", "Count Formatting")
+                .render(|this, _, _| this.count_formatting()),
+        ]
     }
 }
 
 impl BadgeComponentView {
-    /// Example code for the Badge component
+    // Example code for the Badge component
 
     fn badge_with_count(&self) -> AnyElement {
         h_flex()
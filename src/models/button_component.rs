@@ -2,52 +2,48 @@ use gpui::*;
 use gpui_component::button::{Button, ButtonCustomVariant, ButtonGroup, ButtonVariants};
 use gpui_component::*;
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
 
 pub struct ButtonComponentView;
 
 impl ComponentMeta for ButtonComponentView {
+    const NAME: &'static str = "Button";
+    const CATEGORY: ComponentCategory = ComponentCategory::General;
     const DESCRIPTION: &'static str = "The Button element with multiple variants, sizes, and states. \nSupports icons, loading states, and can be grouped together.";
     const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/button";
-}
 
-impl Render for ButtonComponentView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Basic Button"))
-            .child(self.basic_button())
-            .child(subtitle("Variants"))
-            .child(self.variants())
-            .child(subtitle("Outline Buttons"))
-            .child(self.outline_buttons())
-            .child(subtitle("Compact Button"))
-            .child(self.compact_button())
-            .child(subtitle("Sizeable"))
-            .child(self.sizeable())
-            .child(subtitle("With Icons"))
-            .child(self.with_icons())
-            .child(subtitle("With a dropdown caret icon"))
-            .child(self.with_a_dropdown_caret_icon())
-            .child(subtitle("Button States"))
-            .child(self.button_states())
-            .child(subtitle("Button Group"))
-            .child(self.button_group())
-            .child(subtitle("Toggle Button Group"))
-            .child(self.toggle_button_group())
-            .child(subtitle("Custom Variant"))
-            .child(self.custom_variant(_cx))
-            .child(subtitle("With Tooltip"))
-            .child(self.with_tooltip())
-            .child(subtitle("Custom Children"))
-            .child(self.custom_children())
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_button", "Basic Button").render(|this, _, _| this.basic_button()),
+            Self::example("variants", "Variants").render(|this, _, _| this.variants()),
+            Self::example("outline_buttons", "Outline Buttons")
+                .render(|this, _, _| this.outline_buttons()),
+            Self::example("compact_button", "Compact Button")
+                .render(|this, _, _| this.compact_button()),
+            Self::example("sizeable", "Sizeable").render(|this, _, _| this.sizeable()),
+            Self::example("with_icons", "With Icons").render(|this, _, _| this.with_icons()),
+            Self::example("with_a_dropdown_caret_icon", "With a dropdown caret icon")
+                .render(|this, _, _| this.with_a_dropdown_caret_icon()),
+            Self::example("button_states", "Button States")
+                .render(|this, _, _| this.button_states()),
+            Self::example("button_group", "Button Group").render(|this, _, _| this.button_group()),
+            Self::example("toggle_button_group", "Toggle Button Group")
+                .render(|this, _, _| this.toggle_button_group()),
+            Self::example("custom_variant", "Custom Variant")
+                .render(|this, _, cx| this.custom_variant(cx)),
+            Self::example("with_tooltip", "With Tooltip").render(|this, _, _| this.with_tooltip()),
+            Self::example("custom_children", "Custom Children")
+                .render(|this, _, _| this.custom_children()),
+        ]
     }
 }
 
 impl ButtonComponentView {
-    /// Example code for the Button component
+    // Example code for the Button component
 
     fn basic_button(&self) -> AnyElement {
         Button::new("my-button")
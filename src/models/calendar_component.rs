@@ -5,69 +5,71 @@ use gpui::*;
 use gpui_component::calendar::{Calendar, CalendarEvent, CalendarState, Date, Matcher};
 use gpui_component::*;
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
 
 pub struct CalendarComponentView;
 
 impl ComponentMeta for CalendarComponentView {
+    const NAME: &'static str = "Calendar";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataDisplay;
     const DESCRIPTION: &'static str = "A standalone calendar component that provides a rich interface for date selection and navigation. \nSupports single date selection, date range selection, and multiple month views.";
     const LINK: &'static str =
         "https://longbridge.github.io/gpui-component/docs/components/calendar";
-}
 
-impl Render for CalendarComponentView {
-    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Basic Calendar"))
-            .child(self.basic_calendar(window, cx))
-            .child(subtitle("Calendar with Initial Date"))
-            .child(self.calendar_with_initial_date(window, cx))
-            .child(subtitle("Date Range Calendar"))
-            .child(self.date_range_calendar(window, cx))
-            .child(subtitle("Multiple Months Display"))
-            .child(self.multiple_months_display(window, cx))
-            .child(subtitle("Calendar Sizes"))
-            .child(self.calendar_sizes(window, cx))
-            .child(subtitle("Disabled Weekends"))
-            .child(self.disabled_weekends(window, cx))
-            .child(subtitle("Disabled Specific Weekdays"))
-            .child(self.disabled_specific_weekdays(window, cx))
-            .child(subtitle("Disabled Date Range"))
-            .child(self.disabled_date_range(window, cx))
-            .child(subtitle("Disabled Date Interval"))
-            .child(self.disabled_date_interval(window, cx))
-            .child(subtitle("Custom Disabled Dates"))
-            .child(self.custom_disabled_dates(window, cx))
-            .child(subtitle("Custom Year Range"))
-            .child(self.custom_year_range(window, cx))
-            .child(subtitle("Handle Selection Events"))
-            .child(self.handle_selection_events(window, cx))
-            .child(subtitle("Business Days Only Calendar"))
-            .child(self.business_days_only_calendar(window, cx))
-            .child(subtitle("Holiday Calendar"))
-            .child(self.holiday_calendar(window, cx))
-            .child(subtitle("Multi-Month Range Selector"))
-            .child(self.multi_month_range_selector(window, cx))
-            .child(subtitle("Quarterly View Calendar"))
-            .child(self.quarterly_view_calendar(window, cx))
-            .child(subtitle("Custom Styling"))
-            .child(self.custom_styling(window, cx))
-            .child(subtitle("Event Planning Calendar"))
-            .child(self.event_planning_calendar(window, cx))
-            .child(subtitle("Vacation Booking Calendar"))
-            .child(self.vacation_booking_calendar(window, cx))
-            .child(subtitle("Report Date Range Selector"))
-            .child(self.report_date_range_selector(window, cx))
-            .child(subtitle("Availability Calendar"))
-            .child(self.availability_calendar(window, cx))
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_calendar", "Basic Calendar")
+                .render(|this, window, cx| this.basic_calendar(window, cx)),
+            Self::example("calendar_with_initial_date", "Calendar with Initial Date")
+                .render(|this, window, cx| this.calendar_with_initial_date(window, cx)),
+            Self::example("date_range_calendar", "Date Range Calendar")
+                .render(|this, window, cx| this.date_range_calendar(window, cx)),
+            Self::example("multiple_months_display", "Multiple Months Display")
+                .render(|this, window, cx| this.multiple_months_display(window, cx)),
+            Self::example("calendar_sizes", "Calendar Sizes")
+                .render(|this, window, cx| this.calendar_sizes(window, cx)),
+            Self::example("disabled_weekends", "Disabled Weekends")
+                .render(|this, window, cx| this.disabled_weekends(window, cx)),
+            Self::example("disabled_specific_weekdays", "Disabled Specific Weekdays")
+                .render(|this, window, cx| this.disabled_specific_weekdays(window, cx)),
+            Self::example("disabled_date_range", "Disabled Date Range")
+                .render(|this, window, cx| this.disabled_date_range(window, cx)),
+            Self::example("disabled_date_interval", "Disabled Date Interval")
+                .render(|this, window, cx| this.disabled_date_interval(window, cx)),
+            Self::example("custom_disabled_dates", "Custom Disabled Dates")
+                .render(|this, window, cx| this.custom_disabled_dates(window, cx)),
+            Self::example("custom_year_range", "Custom Year Range")
+                .render(|this, window, cx| this.custom_year_range(window, cx)),
+            Self::example("handle_selection_events", "Handle Selection Events")
+                .render(|this, window, cx| this.handle_selection_events(window, cx)),
+            Self::example("business_days_only_calendar", "Business Days Only Calendar")
+                .render(|this, window, cx| this.business_days_only_calendar(window, cx)),
+            Self::example("holiday_calendar", "Holiday Calendar")
+                .render(|this, window, cx| this.holiday_calendar(window, cx)),
+            Self::example("multi_month_range_selector", "Multi-Month Range Selector")
+                .render(|this, window, cx| this.multi_month_range_selector(window, cx)),
+            Self::example("quarterly_view_calendar", "Quarterly View Calendar")
+                .render(|this, window, cx| this.quarterly_view_calendar(window, cx)),
+            Self::example("custom_styling", "Custom Styling")
+                .render(|this, window, cx| this.custom_styling(window, cx)),
+            Self::example("event_planning_calendar", "Event Planning Calendar")
+                .render(|this, window, cx| this.event_planning_calendar(window, cx)),
+            Self::example("vacation_booking_calendar", "Vacation Booking Calendar")
+                .render(|this, window, cx| this.vacation_booking_calendar(window, cx)),
+            Self::example("report_date_range_selector", "Report Date Range Selector")
+                .render(|this, window, cx| this.report_date_range_selector(window, cx)),
+            Self::example("availability_calendar", "Availability Calendar")
+                .render(|this, window, cx| this.availability_calendar(window, cx)),
+        ]
     }
 }
 
 impl CalendarComponentView {
-    /// Example code for the Calendar component
+    // Example code for the Calendar component
 
     fn basic_calendar(&self, window: &mut Window, cx: &mut Context<Self>) -> AnyElement {
         h_flex()
@@ -3,7 +3,8 @@ use gpui::*;
 use gpui_component::chart::{AreaChart, BarChart, CandlestickChart, LineChart, PieChart};
 use gpui_component::*;
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+use crate::preferences::Preferences;
 
 pub struct ChartComponentView {
     date_data: Vec<DateValue>,
@@ -16,46 +17,6 @@ pub struct ChartComponentView {
 }
 
 impl ChartComponentView {
-    pub fn new() -> Self {
-        Self {
-            date_data: vec![
-                DateValue {
-                    date: "2023-01".to_string(),
-                    value: 200.0,
-                },
-                DateValue {
-                    date: "2023-02".to_string(),
-                    value: 250.0,
-                },
-                DateValue {
-                    date: "2023-03".to_string(),
-                    value: 220.0,
-                },
-            ],
-            chart_type: ChartType::Line,
-            time_range: TimeRange {
-                start: "2023-01".to_string(),
-                end: "2023-12".to_string(),
-            },
-            timestamp_data: vec![
-                TimestampValue {
-                    timestamp: "T0".to_string(),
-                    value: 100.0,
-                },
-                TimestampValue {
-                    timestamp: "T1".to_string(),
-                    value: 150.0,
-                },
-                TimestampValue {
-                    timestamp: "T2".to_string(),
-                    value: 120.0,
-                },
-            ],
-            max_points: 20,
-            counter: 3,
-        }
-    }
-
     fn filtered_data(&self) -> Vec<DateValue> {
         self.date_data
             .iter()
@@ -80,6 +41,11 @@ impl ChartComponentView {
 
                 let should_continue = this
                     .update(cx, |view, cx| {
+                        // Paused from the Settings page
+                        if !Preferences::global(cx).chart_animation {
+                            return true;
+                        }
+
                         // Generate a new data point with wave-like variation
                         let last_value =
                             view.timestamp_data.last().map(|p| p.value).unwrap_or(100.0);
@@ -109,69 +75,108 @@ impl ChartComponentView {
 }
 
 impl ComponentMeta for ChartComponentView {
+    const NAME: &'static str = "Chart";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataDisplay;
     const DESCRIPTION: &'static str = "A comprehensive charting library providing Line, Bar, Area, Pie, and Candlestick charts for data visualization. \nThe charts feature smooth animations, customizable styling, tooltips, legends, \nand automatic theming that adapts to your application's theme.";
     const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/chart";
-}
 
-impl Render for ChartComponentView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Basic Line Chart"))
-            .child(self.basic_line_chart())
-            .child(subtitle("Line Chart Variants"))
-            .child(self.line_chart_variants(_cx))
-            .child(subtitle("Tick Control"))
-            .child(self.tick_control())
-            .child(subtitle("Basic Bar Chart"))
-            .child(self.basic_bar_chart())
-            .child(subtitle("Bar Chart Customization"))
-            .child(self.bar_chart_customization(_cx))
-            .child(subtitle("Basic Area Chart"))
-            .child(self.basic_area_chart())
-            .child(subtitle("Stacked Area Charts"))
-            .child(self.stacked_area_charts(_cx))
-            .child(subtitle("Area Chart Styling"))
-            .child(self.area_chart_styling(_cx))
-            .child(subtitle("Basic Pie Chart"))
-            .child(self.basic_pie_chart())
-            .child(subtitle("Donut Chart"))
-            .child(self.donut_chart())
-            .child(subtitle("Pie Chart Customization"))
-            .child(self.pie_chart_customization())
-            .child(subtitle("Basic Candlestick Chart"))
-            .child(self.basic_candlestick_chart())
-            .child(subtitle("Candlestick Chart Customization"))
-            .child(self.candlestick_chart_customization())
-            .child(subtitle("Container Setup"))
-            .child(self.container_setup(_cx))
-            .child(subtitle("Theme Integration"))
-            .child(self.theme_integration(_cx))
-            .child(subtitle("Sales Dashboard"))
-            .child(self.sales_dashboard(_cx))
-            .child(subtitle("Multi-Series Time Chart"))
-            .child(self.multi_series_time_chart(_cx))
-            .child(subtitle("Financial Chart"))
-            .child(self.financial_chart(_cx))
-            .child(subtitle("Color Schemes"))
-            .child(self.color_schemes(_cx))
-            .child(subtitle("Responsive Design"))
-            .child(self.responsive_design())
-            .child(subtitle("Large Datasets"))
-            .child(self.large_datasets())
-            .child(subtitle("Memory Optimization"))
-            .child(self.memory_optimization())
-            .child(subtitle("With State Management"))
-            .child(self.with_state_management())
-            .child(subtitle("Real-time Updates"))
-            .child(self.real_time_updates(_cx))
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self {
+            date_data: vec![
+                DateValue {
+                    date: "2023-01".to_string(),
+                    value: 200.0,
+                },
+                DateValue {
+                    date: "2023-02".to_string(),
+                    value: 250.0,
+                },
+                DateValue {
+                    date: "2023-03".to_string(),
+                    value: 220.0,
+                },
+            ],
+            chart_type: ChartType::Line,
+            time_range: TimeRange {
+                start: "2023-01".to_string(),
+                end: "2023-12".to_string(),
+            },
+            timestamp_data: vec![
+                TimestampValue {
+                    timestamp: "T0".to_string(),
+                    value: 100.0,
+                },
+                TimestampValue {
+                    timestamp: "T1".to_string(),
+                    value: 150.0,
+                },
+                TimestampValue {
+                    timestamp: "T2".to_string(),
+                    value: 120.0,
+                },
+            ],
+            max_points: 20,
+            counter: 3,
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_line_chart", "Basic Line Chart")
+                .render(|this, _, _| this.basic_line_chart()),
+            Self::example("line_chart_variants", "Line Chart Variants")
+                .render(|this, _, cx| this.line_chart_variants(cx)),
+            Self::example("tick_control", "Tick Control").render(|this, _, _| this.tick_control()),
+            Self::example("basic_bar_chart", "Basic Bar Chart")
+                .render(|this, _, _| this.basic_bar_chart()),
+            Self::example("bar_chart_customization", "Bar Chart Customization")
+                .render(|this, _, cx| this.bar_chart_customization(cx)),
+            Self::example("basic_area_chart", "Basic Area Chart")
+                .render(|this, _, _| this.basic_area_chart()),
+            Self::example("stacked_area_charts", "Stacked Area Charts")
+                .render(|this, _, cx| this.stacked_area_charts(cx)),
+            Self::example("area_chart_styling", "Area Chart Styling")
+                .render(|this, _, cx| this.area_chart_styling(cx)),
+            Self::example("basic_pie_chart", "Basic Pie Chart")
+                .render(|this, _, _| this.basic_pie_chart()),
+            Self::example("donut_chart", "Donut Chart").render(|this, _, _| this.donut_chart()),
+            Self::example("pie_chart_customization", "Pie Chart Customization")
+                .render(|this, _, _| this.pie_chart_customization()),
+            Self::example("basic_candlestick_chart", "Basic Candlestick Chart")
+                .render(|this, _, _| this.basic_candlestick_chart()),
+            Self::example(
+                "candlestick_chart_customization",
+                "Candlestick Chart Customization",
+            )
+            .render(|this, _, _| this.candlestick_chart_customization()),
+            Self::example("container_setup", "Container Setup")
+                .render(|this, _, cx| this.container_setup(cx)),
+            Self::example("theme_integration", "Theme Integration")
+                .render(|this, _, cx| this.theme_integration(cx)),
+            Self::example("sales_dashboard", "Sales Dashboard")
+                .render(|this, _, cx| this.sales_dashboard(cx)),
+            Self::example("multi_series_time_chart", "Multi-Series Time Chart")
+                .render(|this, _, cx| this.multi_series_time_chart(cx)),
+            Self::example("financial_chart", "Financial Chart")
+                .render(|this, _, cx| this.financial_chart(cx)),
+            Self::example("color_schemes", "Color Schemes")
+                .render(|this, _, cx| this.color_schemes(cx)),
+            Self::example("responsive_design", "Responsive Design")
+                .render(|this, _, _| this.responsive_design()),
+            Self::example("large_datasets", "Large Datasets")
+                .render(|this, _, _| this.large_datasets()),
+            Self::example("memory_optimization", "Memory Optimization")
+                .render(|this, _, _| this.memory_optimization()),
+            Self::example("with_state_management", "With State Management")
+                .render(|this, _, _| this.with_state_management()),
+            Self::example("real_time_updates", "Real-time Updates")
+                .render(|this, _, cx| this.real_time_updates(cx)),
+        ]
     }
 }
 
 impl ChartComponentView {
-    /// Example code for the Chart component
+    // Example code for the Chart component
 
     fn basic_line_chart(&self) -> AnyElement {
         let data = vec![
@@ -470,7 +475,7 @@ impl ChartComponentView {
         ];
 
         PieChart::new(data)
-            .value(|d| d.amount as f32)
+            .value(|d| d.amount)
             .outer_radius(100.)
             .into_any_element()
     }
@@ -492,7 +497,7 @@ impl ChartComponentView {
         ];
 
         PieChart::new(data)
-            .value(|d| d.amount as f32)
+            .value(|d| d.amount)
             .outer_radius(100.)
             .inner_radius(60.) // Creates donut effect
             .into_any_element()
@@ -519,14 +524,14 @@ impl ChartComponentView {
             .child(
                 // Custom colors
                 PieChart::new(data.clone())
-                    .value(|d| d.amount as f32)
+                    .value(|d| d.amount)
                     .outer_radius(100.)
                     .color(|d| d.color),
             )
             .child(
                 // With padding between slices
                 PieChart::new(data.clone())
-                    .value(|d| d.amount as f32)
+                    .value(|d| d.amount)
                     .outer_radius(100.)
                     .inner_radius(60.)
                     .pad_angle(4. / 100.), // 4% padding
@@ -1047,17 +1052,26 @@ impl ChartComponentView {
         }
 
         // Simple chart display with auto-updating data
-        div()
-            .h(px(200.))
-            .w_full()
+        v_flex()
+            .gap_2()
             .child(
-                LineChart::new(self.timestamp_data.clone())
-                    .x(|d| d.timestamp.clone())
-                    .y(|d| d.value)
-                    .linear()
-                    .dot()
-                    .stroke(cx.theme().chart_1),
+                div().h(px(200.)).w_full().child(
+                    LineChart::new(self.timestamp_data.clone())
+                        .x(|d| d.timestamp.clone())
+                        .y(|d| d.value)
+                        .linear()
+                        .dot()
+                        .stroke(cx.theme().chart_1),
+                ),
             )
+            .when(!Preferences::global(cx).chart_animation, |this| {
+                this.child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child("Live updates are paused, turn on Chart Animation in Settings."),
+                )
+            })
             .into_any_element()
     }
 }
@@ -2,7 +2,7 @@ use gpui::*;
 use gpui_component::checkbox::Checkbox;
 use gpui_component::*;
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
 
 pub struct CheckboxComponentView {
     is_checked: bool,
@@ -11,48 +11,44 @@ pub struct CheckboxComponentView {
 }
 
 impl ComponentMeta for CheckboxComponentView {
+    const NAME: &'static str = "Checkbox";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataEntry;
     const DESCRIPTION: &'static str = "A checkbox component for binary choices. \nSupports labels, disabled state, and different sizes.";
     const LINK: &'static str =
         "https://longbridge.github.io/gpui-component/docs/components/checkbox";
-}
 
-impl CheckboxComponentView {
-    pub fn new() -> Self {
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
         Self {
             is_checked: false,
             agree_terms: false,
             subscribe: false,
         }
     }
-}
 
-impl Render for CheckboxComponentView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Basic Checkbox"))
-            .child(self.basic_checkbox())
-            .child(subtitle("Controlled Checkbox"))
-            .child(self.controlled_checkbox(_cx))
-            .child(subtitle("Different Sizes"))
-            .child(self.different_sizes())
-            .child(subtitle("Disabled State"))
-            .child(self.disabled_state())
-            .child(subtitle("Without Label"))
-            .child(self.without_label())
-            .child(subtitle("Custom Tab Order"))
-            .child(self.custom_tab_order())
-            .child(subtitle("Checkbox List"))
-            .child(self.checkbox_list())
-            .child(subtitle("Form Integration"))
-            .child(self.form_integration(_cx))
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_checkbox", "Basic Checkbox")
+                .render(|this, _, _| this.basic_checkbox()),
+            Self::example("controlled_checkbox", "Controlled Checkbox")
+                .render(|this, _, cx| this.controlled_checkbox(cx)),
+            Self::example("different_sizes", "Different Sizes")
+                .render(|this, _, _| this.different_sizes()),
+            Self::example("disabled_state", "Disabled State")
+                .render(|this, _, _| this.disabled_state()),
+            Self::example("without_label", "Without Label")
+                .render(|this, _, _| this.without_label()),
+            Self::example("custom_tab_order", "Custom Tab Order")
+                .render(|this, _, _| this.custom_tab_order()),
+            Self::example("checkbox_list", "Checkbox List")
+                .render(|this, _, _| this.checkbox_list()),
+            Self::example("form_integration", "Form Integration")
+                .render(|this, _, cx| this.form_integration(cx)),
+        ]
     }
 }
 
 impl CheckboxComponentView {
-    /// Example code for the Checkbox component
+    // Example code for the Checkbox component
 
     fn basic_checkbox(&self) -> AnyElement {
         h_flex()
@@ -3,43 +3,52 @@ use gpui_component::input::{Input, InputState};
 use gpui_component::*;
 use gpui_component::{clipboard::Clipboard, label::Label};
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
 
-pub struct ClipboardComponentView;
+/// A fake key for the read-only inputs, also shown on the Input page
+pub(crate) const API_KEY: &str = "sk-1234567890abcdef";
+
+pub struct ClipboardComponentView {
+    api_key: Entity<InputState>,
+}
 
 impl ComponentMeta for ClipboardComponentView {
+    const NAME: &'static str = "Clipboard";
+    const CATEGORY: ComponentCategory = ComponentCategory::General;
     const DESCRIPTION: &'static str = "The Clipboard component provides an easy way to copy text or other data to the user's clipboard. \nIt renders as a button with a copy icon that changes to a checkmark when content is successfully copied. \nThe component supports both static values and dynamic content through callback functions.";
     const LINK: &'static str =
         "https://longbridge.github.io/gpui-component/docs/components/clipboard";
-}
 
-impl Render for ClipboardComponentView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Basic Clipboard"))
-            .child(self.basic_clipboard())
-            .child(subtitle("Using Dynamic Values"))
-            .child(self.using_dynamic_values(_cx))
-            .child(subtitle("With Custom Content"))
-            .child(self.with_custom_content())
-            .child(subtitle("In Input Fields"))
-            .child(self.in_input_fields(_window, _cx))
-            .child(subtitle("Simple Text Copy"))
-            .child(self.simple_text_copy())
-            .child(subtitle("ClipbWith User Feedbackoard"))
-            .child(self.with_user_feedback())
-            .child(subtitle("Form Field Integration"))
-            .child(self.form_field_integration())
-            .child(subtitle("Dynamic Content Copy"))
-            .child(self.dynamic_content_copy(_cx))
+    fn create(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            api_key: cx.new(|cx| InputState::new(window, cx).default_value(API_KEY)),
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_clipboard", "Basic Clipboard")
+                .render(|this, _, _| this.basic_clipboard()),
+            Self::example("using_dynamic_values", "Using Dynamic Values")
+                .render(|this, _, cx| this.using_dynamic_values(cx)),
+            Self::example("with_custom_content", "With Custom Content")
+                .render(|this, _, _| this.with_custom_content()),
+            Self::example("in_input_fields", "In Input Fields")
+                .render(|this, window, cx| this.in_input_fields(window, cx)),
+            Self::example("simple_text_copy", "Simple Text Copy")
+                .render(|this, _, _| this.simple_text_copy()),
+            Self::example("with_user_feedback", "With User Feedback")
+                .render(|this, _, _| this.with_user_feedback()),
+            Self::example("form_field_integration", "Form Field Integration")
+                .render(|this, _, _| this.form_field_integration()),
+            Self::example("dynamic_content_copy", "Dynamic Content Copy")
+                .render(|this, _, cx| this.dynamic_content_copy(cx)),
+        ]
     }
 }
 
 impl ClipboardComponentView {
-    /// Example code for the Clipboard component
+    // Example code for the Clipboard component
 
     fn basic_clipboard(&self) -> AnyElement {
         Clipboard::new("my-clipboard")
@@ -114,27 +123,19 @@ impl ClipboardComponentView {
     }
 
     fn form_field_integration(&self) -> AnyElement {
-        // let input_state =
-        //     cx.new(|cx| InputState::new(window, cx).default_value("https://github.com"));
-        // let api_key = "sk-1234567890abcdef";
-
-        // h_flex()
-        //     .gap_2()
-        //     .items_center()
-        //     .child(Label::new("API Key:"))
-        //     .child(
-        //         Input::new(&input_state)
-        //             .value(api_key)
-        //             .readonly(true)
-        //             .suffix(Clipboard::new("api-key-copy").value(api_key).on_copied(
-        //                 |_, window, cx| window.push_notification("API key copied!", cx),
-        //             )),
-        //     )
-        //     .into_any_element()
-
-        div()
-            .text_color(rgb(0x666666))
-            .child("Not implemented yet")
+        // The input is disabled to make it read-only, its text can still be
+        // selected and copied
+        h_flex()
+            .gap_2()
+            .items_center()
+            .child(Label::new("API Key:"))
+            .child(
+                Input::new(&self.api_key).disabled(true).suffix(
+                    Clipboard::new("api-key-copy")
+                        .value(API_KEY)
+                        .on_copied(|_, window, cx| window.push_notification("API key copied!", cx)),
+                ),
+            )
             .into_any_element()
     }
 
@@ -4,37 +4,30 @@ use gpui_component::button::ButtonVariants;
 use gpui_component::*;
 use gpui_component::{button::Button, collapsible::Collapsible};
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
 
 pub struct CollapsibleComponentView {
     open: bool,
 }
 
-impl CollapsibleComponentView {
-    pub fn new() -> Self {
-        Self { open: false }
-    }
-}
-
 impl ComponentMeta for CollapsibleComponentView {
+    const NAME: &'static str = "Collapsible";
+    const CATEGORY: ComponentCategory = ComponentCategory::Layout;
     const DESCRIPTION: &'static str = "An interactive element which expands/collapses.";
     const LINK: &'static str =
         "https://longbridge.github.io/gpui-component/docs/components/collapsible";
-}
 
-impl Render for CollapsibleComponentView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Basic Use"))
-            .child(self.basic_use(_cx))
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self { open: false }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![Self::example("basic_use", "Basic Use").render(|this, _, cx| this.basic_use(cx))]
     }
 }
 
 impl CollapsibleComponentView {
-    /// Example code for the Collapsible component
+    // Example code for the Collapsible component
 
     fn basic_use(&self, cx: &mut Context<Self>) -> AnyElement {
         Collapsible::new()
@@ -1,34 +1,59 @@
 use gpui::*;
 use gpui_component::button::{Button, ButtonVariants};
 use gpui_component::color_picker::{ColorPicker, ColorPickerEvent, ColorPickerState};
+use gpui_component::notification::Notification;
+use gpui_component::scroll::ScrollableElement;
 use gpui_component::*;
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+use crate::themes::{self, THEME_TOKENS, ThemeToken};
 
 pub struct ColorPickerComponentView {
-    primary_color: Entity<ColorPickerState>,
-    secondary_color: Entity<ColorPickerState>,
-    accent_color: Entity<ColorPickerState>,
+    /// One picker per theme color, editing the global theme live
+    token_pickers: Vec<(&'static ThemeToken, Entity<ColorPickerState>)>,
 
     colors: Vec<Entity<ColorPickerState>>,
+    _subscriptions: Vec<Subscription>,
 }
 
 impl ColorPickerComponentView {
     pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
-        let primary_color =
-            cx.new(|cx| ColorPickerState::new(window, cx).default_value(cx.theme().primary));
-
-        let secondary_color =
-            cx.new(|cx| ColorPickerState::new(window, cx).default_value(cx.theme().secondary));
+        let mut _subscriptions = Vec::new();
+        let token_pickers = THEME_TOKENS
+            .iter()
+            .map(|token| {
+                let color = (token.get)(&cx.theme().colors);
+                let picker = cx.new(|cx| ColorPickerState::new(window, cx).default_value(color));
+                _subscriptions.push(cx.subscribe(&picker, move |_, _, ev, cx| {
+                    if let ColorPickerEvent::Change(Some(color)) = ev {
+                        themes::set_token(token, *color, cx);
+                    }
+                }));
+                (token, picker)
+            })
+            .collect();
 
-        let accent_color =
-            cx.new(|cx| ColorPickerState::new(window, cx).default_value(cx.theme().accent));
+        // Keep the pickers in sync when the theme changes elsewhere, e.g. the
+        // mode is switched or the theme is reset
+        _subscriptions.push(cx.observe_global_in::<Theme>(window, |this, window, cx| {
+            this.sync_token_pickers(window, cx);
+        }));
 
         Self {
-            primary_color,
-            secondary_color,
-            accent_color,
+            token_pickers,
             colors: Vec::new(),
+            _subscriptions,
+        }
+    }
+
+    fn sync_token_pickers(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        for (token, picker) in &self.token_pickers {
+            let color = (token.get)(&cx.theme().colors);
+            picker.update(cx, |picker, cx| {
+                if picker.value() != Some(color) {
+                    picker.set_value(color, window, cx);
+                }
+            });
         }
     }
 
@@ -64,48 +89,56 @@ impl ColorPickerComponentView {
 }
 
 impl ComponentMeta for ColorPickerComponentView {
+    const NAME: &'static str = "Color Picker";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataEntry;
     const DESCRIPTION: &'static str = "A versatile color picker component that provides an intuitive interface for color selection. \nFeatures include color palettes, hex input, featured colors, and support for various color formats including RGB, HSL, and hex values with alpha channel support.";
     const LINK: &'static str =
         "https://longbridge.github.io/gpui-component/docs/components/color-picker";
-}
 
-impl Render for ColorPickerComponentView {
-    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Basic Color Picker"))
-            .child(self.basic_color_picker(window, cx))
-            .child(subtitle("With Event Handling"))
-            .child(self.with_event_handling(window, cx))
-            .child(subtitle("Setting Default Color"))
-            .child(self.setting_default_color(window, cx))
-            .child(subtitle("Different Sizes"))
-            .child(self.different_sizes(window, cx))
-            .child(subtitle("With Custom Featured Colors"))
-            .child(self.with_custom_featured_colors(window, cx))
-            .child(subtitle("With Icon Instead of Color Square"))
-            .child(self.with_icon_instead_of_color_square(window, cx))
-            .child(subtitle("With Label"))
-            .child(self.with_label(window, cx))
-            .child(subtitle("Custom Anchor Position"))
-            .child(self.custom_anchor_position(window, cx))
-            .child(subtitle("Color Theme Editor"))
-            .child(self.color_theme_editor(window, cx))
-            .child(subtitle("Brand Color Selector"))
-            .child(self.brand_color_selector(window, cx))
-            .child(subtitle("Toolbar Color Picker"))
-            .child(self.toolbar_color_picker(window, cx))
-            .child(subtitle("Color Palette Builder"))
-            .child(self.color_palette_builder(window, cx))
-            .child(subtitle("With Color Validation"))
-            .child(self.with_color_validation(window, cx))
+    fn create(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self::new(window, cx)
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_color_picker", "Basic Color Picker")
+                .render(|this, window, cx| this.basic_color_picker(window, cx)),
+            Self::example("with_event_handling", "With Event Handling")
+                .render(|this, window, cx| this.with_event_handling(window, cx)),
+            Self::example("setting_default_color", "Setting Default Color")
+                .render(|this, window, cx| this.setting_default_color(window, cx)),
+            Self::example("different_sizes", "Different Sizes")
+                .render(|this, window, cx| this.different_sizes(window, cx)),
+            Self::example("with_custom_featured_colors", "With Custom Featured Colors")
+                .render(|this, window, cx| this.with_custom_featured_colors(window, cx)),
+            Self::example(
+                "with_icon_instead_of_color_square",
+                "With Icon Instead of Color Square",
+            )
+            .render(|this, window, cx| this.with_icon_instead_of_color_square(window, cx)),
+            Self::example("with_label", "With Label")
+                .render(|this, window, cx| this.with_label(window, cx)),
+            Self::example("custom_anchor_position", "Custom Anchor Position")
+                .render(|this, window, cx| this.custom_anchor_position(window, cx)),
+            Self::example("color_theme_editor", "Color Theme Editor")
+                .description(
+                    "Edits the colors of the active theme live, export them to a theme file or reset to the default theme.",
+                )
+                .render(|this, window, cx| this.color_theme_editor(window, cx)),
+            Self::example("brand_color_selector", "Brand Color Selector")
+                .render(|this, window, cx| this.brand_color_selector(window, cx)),
+            Self::example("toolbar_color_picker", "Toolbar Color Picker")
+                .render(|this, window, cx| this.toolbar_color_picker(window, cx)),
+            Self::example("color_palette_builder", "Color Palette Builder")
+                .render(|this, window, cx| this.color_palette_builder(window, cx)),
+            Self::example("with_color_validation", "With Color Validation")
+                .render(|this, window, cx| this.with_color_validation(window, cx)),
+        ]
     }
 }
 
 impl ColorPickerComponentView {
-    /// Example code for the ColorPicker component
+    // Example code for the ColorPicker component
 
     fn basic_color_picker(&self, window: &mut Window, cx: &mut Context<Self>) -> AnyElement {
         // Create color picker state
@@ -210,29 +243,59 @@ impl ColorPickerComponentView {
             .into_any_element()
     }
 
-    fn color_theme_editor(&self, _window: &mut Window, _cx: &mut Context<Self>) -> AnyElement {
+    fn color_theme_editor(&self, _window: &mut Window, cx: &mut Context<Self>) -> AnyElement {
         v_flex()
             .gap_4()
             .child(
                 h_flex()
                     .gap_2()
-                    .items_center()
-                    .child("Primary Color:")
-                    .child(ColorPicker::new(&self.primary_color)),
-            )
-            .child(
-                h_flex()
-                    .gap_2()
-                    .items_center()
-                    .child("Secondary Color:")
-                    .child(ColorPicker::new(&self.secondary_color)),
+                    .child(
+                        Button::new("export-theme")
+                            .small()
+                            .icon(IconName::File)
+                            .label("Export as theme file")
+                            .on_click(cx.listener(|_, _, window, cx| {
+                                // Saves themes/custom-<mode>.json and switches to it
+                                match themes::export_active_theme(cx) {
+                                    Ok(path) => window.push_notification(
+                                        Notification::success(format!(
+                                            "Theme saved to {}",
+                                            path.display()
+                                        )),
+                                        cx,
+                                    ),
+                                    Err(err) => window.push_notification(
+                                        Notification::error(format!("{:#}", err)),
+                                        cx,
+                                    ),
+                                }
+                            })),
+                    )
+                    .child(
+                        Button::new("reset-theme")
+                            .small()
+                            .ghost()
+                            .icon(IconName::Undo)
+                            .label("Reset to default")
+                            .on_click(cx.listener(|_, _, window, cx| {
+                                themes::reset_to_default(Some(window), cx);
+                            })),
+                    ),
             )
             .child(
-                h_flex()
-                    .gap_2()
-                    .items_center()
-                    .child("Accent Color:")
-                    .child(ColorPicker::new(&self.accent_color)),
+                // Every theme color, changes apply to all open pages right away
+                v_flex()
+                    .max_h(px(360.))
+                    .gap_1()
+                    .overflow_y_scrollbar()
+                    .children(self.token_pickers.iter().map(|(token, picker)| {
+                        h_flex()
+                            .justify_between()
+                            .gap_2()
+                            .pr_3()
+                            .child(div().text_sm().child(token.key))
+                            .child(ColorPicker::new(picker).small())
+                    })),
             )
             .into_any_element()
     }
@@ -6,73 +6,81 @@ use gpui_component::{
     date_picker::{DatePicker, DatePickerEvent, DatePickerState, DateRangePreset},
 };
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
 
 pub struct DatePickerComponentView;
 
 impl ComponentMeta for DatePickerComponentView {
+    const NAME: &'static str = "Date Picker";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataEntry;
     const DESCRIPTION: &'static str = "A flexible date picker component with calendar interface that supports single date selection, \ndate range selection, custom date formatting, disabled dates, and preset ranges.";
     const LINK: &'static str =
         "https://longbridge.github.io/gpui-component/docs/components/date-picker";
-}
 
-impl Render for DatePickerComponentView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Basic Date Picker"))
-            .child(self.basic_date_picker(_window, _cx))
-            .child(subtitle("With Initial Date"))
-            .child(self.with_initial_date(_window, _cx))
-            .child(subtitle("Date Range Picker"))
-            .child(self.date_range_picker(_window, _cx))
-            .child(subtitle("With Custom Date Format"))
-            .child(self.with_custom_date_format(_window, _cx))
-            .child(subtitle("With Placeholder"))
-            .child(self.with_placeholder(_window, _cx))
-            .child(subtitle("Cleanable Date Picker"))
-            .child(self.cleanable_date_picker(_window, _cx))
-            .child(subtitle("Different Sizes"))
-            .child(self.different_sizes(_window, _cx))
-            .child(subtitle("Disabled State"))
-            .child(self.disabled_state(_window, _cx))
-            .child(subtitle("Custom Appearance"))
-            .child(self.custom_appearance(_window, _cx))
-            .child(subtitle("Disabled Weekends"))
-            .child(self.disabled_weekends(_window, _cx))
-            .child(subtitle("Disabled Date Range"))
-            .child(self.disabled_date_range(_window, _cx))
-            .child(subtitle("Disabled Date Interval"))
-            .child(self.disabled_date_interval(_window, _cx))
-            .child(subtitle("Custom Disabled Dates"))
-            .child(self.custom_disabled_dates(_window, _cx))
-            .child(subtitle("Single Date Presets"))
-            .child(self.single_date_presets(_window, _cx))
-            .child(subtitle("Date Range Presets"))
-            .child(self.date_range_presets(_window, _cx))
-            .child(subtitle("Handle Date Selection Events"))
-            .child(self.handle_date_selection_events(_window, _cx))
-            .child(subtitle("Multiple Months Display"))
-            .child(self.multiple_months_display(_window, _cx))
-            .child(subtitle("Business Days Only"))
-            .child(self.business_days_only(_window, _cx))
-            .child(subtitle("Date Range with Max Duration"))
-            .child(self.date_range_with_max_duration(_window, _cx))
-            .child(subtitle("Quarter Presets"))
-            .child(self.quarter_presets(_window, _cx))
-            .child(subtitle("Event Date Picker"))
-            .child(self.event_date_picker(_window, _cx))
-            .child(subtitle("Booking System Date Range"))
-            .child(self.booking_system_date_range(_window, _cx))
-            .child(subtitle("Financial Period Selector"))
-            .child(self.financial_period_selector(_window, _cx))
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_date_picker", "Basic Date Picker")
+                .render(|this, window, cx| this.basic_date_picker(window, cx)),
+            Self::example("with_initial_date", "With Initial Date")
+                .render(|this, window, cx| this.with_initial_date(window, cx)),
+            Self::example("date_range_picker", "Date Range Picker")
+                .render(|this, window, cx| this.date_range_picker(window, cx)),
+            Self::example("with_custom_date_format", "With Custom Date Format")
+                .render(|this, window, cx| this.with_custom_date_format(window, cx)),
+            Self::example("with_placeholder", "With Placeholder")
+                .render(|this, window, cx| this.with_placeholder(window, cx)),
+            Self::example("cleanable_date_picker", "Cleanable Date Picker")
+                .render(|this, window, cx| this.cleanable_date_picker(window, cx)),
+            Self::example("different_sizes", "Different Sizes")
+                .render(|this, window, cx| this.different_sizes(window, cx)),
+            Self::example("disabled_state", "Disabled State")
+                .render(|this, window, cx| this.disabled_state(window, cx)),
+            Self::example("custom_appearance", "Custom Appearance")
+                .render(|this, window, cx| this.custom_appearance(window, cx)),
+            Self::example("disabled_weekends", "Disabled Weekends")
+                .render(|this, window, cx| this.disabled_weekends(window, cx)),
+            Self::example("disabled_date_range", "Disabled Date Range")
+                .render(|this, window, cx| this.disabled_date_range(window, cx)),
+            Self::example("disabled_date_interval", "Disabled Date Interval")
+                .render(|this, window, cx| this.disabled_date_interval(window, cx)),
+            Self::example("custom_disabled_dates", "Custom Disabled Dates")
+                .render(|this, window, cx| this.custom_disabled_dates(window, cx)),
+            Self::example("single_date_presets", "Single Date Presets")
+                .render(|this, window, cx| this.single_date_presets(window, cx)),
+            Self::example("date_range_presets", "Date Range Presets")
+                .render(|this, window, cx| this.date_range_presets(window, cx)),
+            Self::example(
+                "handle_date_selection_events",
+                "Handle Date Selection Events",
+            )
+            .render(|this, window, cx| this.handle_date_selection_events(window, cx)),
+            Self::example("multiple_months_display", "Multiple Months Display")
+                .render(|this, window, cx| this.multiple_months_display(window, cx)),
+            Self::example("business_days_only", "Business Days Only")
+                .render(|this, window, cx| this.business_days_only(window, cx)),
+            Self::example(
+                "date_range_with_max_duration",
+                "Date Range with Max Duration",
+            )
+            .render(|this, window, cx| this.date_range_with_max_duration(window, cx)),
+            Self::example("quarter_presets", "Quarter Presets")
+                .render(|this, window, cx| this.quarter_presets(window, cx)),
+            Self::example("event_date_picker", "Event Date Picker")
+                .render(|this, window, cx| this.event_date_picker(window, cx)),
+            Self::example("booking_system_date_range", "Booking System Date Range")
+                .render(|this, window, cx| this.booking_system_date_range(window, cx)),
+            Self::example("financial_period_selector", "Financial Period Selector")
+                .render(|this, window, cx| this.financial_period_selector(window, cx)),
+        ]
     }
 }
 
 impl DatePickerComponentView {
-    /// Example code for the Date Picker component
+    // Example code for the Date Picker component
 
     fn basic_date_picker(&self, window: &mut Window, cx: &mut Context<Self>) -> AnyElement {
         let date_picker = cx.new(|cx| DatePickerState::new(window, cx));
@@ -395,13 +403,12 @@ impl DatePickerComponentView {
 
     fn event_date_picker(&self, window: &mut Window, cx: &mut Context<Self>) -> AnyElement {
         let event_date = cx.new(|cx| {
-            let picker = DatePickerState::new(window, cx)
+            DatePickerState::new(window, cx)
                 .date_format("%B %d, %Y")
                 .disabled_matcher(gpui_component::calendar::Matcher::custom(|date| {
                     // Disable past dates
                     *date < Local::now().naive_local().date()
-                }));
-            picker
+                }))
         });
 
         DatePicker::new(&event_date)
@@ -415,7 +422,7 @@ impl DatePickerComponentView {
 
         let today = Local::now().naive_local().date();
         let this_month_start = NaiveDate::from_ymd_opt(today.year(), today.month(), 1).unwrap();
-        let this_month_end = quarter_end(today.year(), ((today.month() as u32 - 1) / 3) + 1);
+        let this_month_end = quarter_end(today.year(), ((today.month() - 1) / 3) + 1);
         let days_to_weekend = (5 - today.weekday().number_from_monday() as i64 + 7) % 7;
         let this_weekend_start = if days_to_weekend == 0 {
             today
@@ -3,55 +3,69 @@ use gpui_component::description_list::{DescriptionItem, DescriptionList};
 use gpui_component::text::TextView;
 use gpui_component::*;
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
 
 pub struct DescriptionListComponentView;
 
 impl ComponentMeta for DescriptionListComponentView {
+    const NAME: &'static str = "Description List";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataDisplay;
     const DESCRIPTION: &'static str = "A versatile component for displaying key-value pairs in a structured, organized layout. \nSupports both horizontal and vertical layouts, multiple columns, borders, and different sizes. \nPerfect for showing detailed information like metadata, specifications, or summary data.";
     const LINK: &'static str =
         "https://longbridge.github.io/gpui-component/docs/components/description-list";
-}
 
-impl Render for DescriptionListComponentView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Basic Description List"))
-            .child(self.basic_description_list())
-            .child(subtitle("Using DescriptionItem Builder"))
-            .child(self.using_description_item_builder())
-            .child(subtitle("Different Layouts"))
-            .child(self.different_layouts())
-            .child(subtitle("Multiple Columns with Spans"))
-            .child(self.multiple_columns_with_spans())
-            .child(subtitle("With Dividers"))
-            .child(self.with_dividers())
-            .child(subtitle("Different Sizes"))
-            .child(self.different_sizes())
-            .child(subtitle("Without Borders"))
-            .child(self.without_borders())
-            .child(subtitle("Custom Label Width (Horizontal Layout)"))
-            .child(self.custom_label_width())
-            .child(subtitle("Rich Content with Custom Elements"))
-            .child(self.rich_content_with_custom_elements(_window, _cx))
-            .child(subtitle("Complex Example with Mixed Content"))
-            .child(self.complex_example_with_mixed_content())
-            .child(subtitle("User Profile Information"))
-            .child(self.user_profile_information())
-            .child(subtitle("System Information"))
-            .child(self.system_information())
-            .child(subtitle("Product Specifications"))
-            .child(self.product_specifications())
-            .child(subtitle("Configuration Settings"))
-            .child(self.configuration_settings())
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_description_list", "Basic Description List")
+                .render(|this, _, _| this.basic_description_list()),
+            Self::example(
+                "using_description_item_builder",
+                "Using DescriptionItem Builder",
+            )
+            .render(|this, _, _| this.using_description_item_builder()),
+            Self::example("different_layouts", "Different Layouts")
+                .render(|this, _, _| this.different_layouts()),
+            Self::example("multiple_columns_with_spans", "Multiple Columns with Spans")
+                .render(|this, _, _| this.multiple_columns_with_spans()),
+            Self::example("with_dividers", "With Dividers")
+                .render(|this, _, _| this.with_dividers()),
+            Self::example("different_sizes", "Different Sizes")
+                .render(|this, _, _| this.different_sizes()),
+            Self::example("without_borders", "Without Borders")
+                .render(|this, _, _| this.without_borders()),
+            Self::example(
+                "custom_label_width",
+                "Custom Label Width (Horizontal Layout)",
+            )
+            .render(|this, _, _| this.custom_label_width()),
+            Self::example(
+                "rich_content_with_custom_elements",
+                "Rich Content with Custom Elements",
+            )
+            .render(|this, window, cx| this.rich_content_with_custom_elements(window, cx)),
+            Self::example(
+                "complex_example_with_mixed_content",
+                "Complex Example with Mixed Content",
+            )
+            .render(|this, _, _| this.complex_example_with_mixed_content()),
+            Self::example("user_profile_information", "User Profile Information")
+                .render(|this, _, _| this.user_profile_information()),
+            Self::example("system_information", "System Information")
+                .render(|this, _, _| this.system_information()),
+            Self::example("product_specifications", "Product Specifications")
+                .render(|this, _, _| this.product_specifications()),
+            Self::example("configuration_settings", "Configuration Settings")
+                .render(|this, _, _| this.configuration_settings()),
+        ]
     }
 }
 
 impl DescriptionListComponentView {
-    /// Example code for the Description List component
+    // Example code for the Description List component
 
     fn basic_description_list(&self) -> AnyElement {
         v_flex()
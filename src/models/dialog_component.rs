@@ -6,54 +6,56 @@ use gpui_component::text::TextView;
 use gpui_component::*;
 use gpui_component::{WindowExt, button::Button};
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
 
 pub struct DialogComponentView;
 
 impl ComponentMeta for DialogComponentView {
+    const NAME: &'static str = "Dialog";
+    const CATEGORY: ComponentCategory = ComponentCategory::Overlay;
     const DESCRIPTION: &'static str = "Dialog component for creating dialogs, confirmations, and alerts. \nSupports overlay, keyboard shortcuts, and various customizations.";
     const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/dialog";
-}
 
-impl Render for DialogComponentView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Basic Dialog"))
-            .child(self.basic_dialog())
-            .child(subtitle("Form Dialog"))
-            .child(self.form_dialog())
-            .child(subtitle("Confirm Dialog"))
-            .child(self.confirm_dialog())
-            .child(subtitle("Alert Dialog"))
-            .child(self.alert_dialog())
-            .child(subtitle("Custom Button Labels"))
-            .child(self.custom_button_labels())
-            .child(subtitle("Dialog with Icon"))
-            .child(self.dialog_with_icon())
-            .child(subtitle("Scrollable Dialog"))
-            .child(self.scrollable_dialog())
-            .child(subtitle("Dialog Options"))
-            .child(self.dialog_options())
-            .child(subtitle("Nested Dialogs"))
-            .child(self.nested_dialogs())
-            .child(subtitle("Custom Styling"))
-            .child(self.custom_styling())
-            .child(subtitle("Custom Padding"))
-            .child(self.custom_padding())
-            .child(subtitle("Close Dialog Programmatically"))
-            .child(self.close_dialog_programmatically())
-            .child(subtitle("Delete Confirmation"))
-            .child(self.delete_confirmation())
-            .child(subtitle("Success Alert"))
-            .child(self.success_alert())
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_dialog", "Basic Dialog").render(|this, _, _| this.basic_dialog()),
+            Self::example("form_dialog", "Form Dialog").render(|this, _, _| this.form_dialog()),
+            Self::example("confirm_dialog", "Confirm Dialog")
+                .render(|this, _, _| this.confirm_dialog()),
+            Self::example("alert_dialog", "Alert Dialog").render(|this, _, _| this.alert_dialog()),
+            Self::example("custom_button_labels", "Custom Button Labels")
+                .render(|this, _, _| this.custom_button_labels()),
+            Self::example("dialog_with_icon", "Dialog with Icon")
+                .render(|this, _, _| this.dialog_with_icon()),
+            Self::example("scrollable_dialog", "Scrollable Dialog")
+                .render(|this, _, _| this.scrollable_dialog()),
+            Self::example("dialog_options", "Dialog Options")
+                .render(|this, _, _| this.dialog_options()),
+            Self::example("nested_dialogs", "Nested Dialogs")
+                .render(|this, _, _| this.nested_dialogs()),
+            Self::example("custom_styling", "Custom Styling")
+                .render(|this, _, _| this.custom_styling()),
+            Self::example("custom_padding", "Custom Padding")
+                .render(|this, _, _| this.custom_padding()),
+            Self::example(
+                "close_dialog_programmatically",
+                "Close Dialog Programmatically",
+            )
+            .render(|this, _, _| this.close_dialog_programmatically()),
+            Self::example("delete_confirmation", "Delete Confirmation")
+                .render(|this, _, _| this.delete_confirmation()),
+            Self::example("success_alert", "Success Alert")
+                .render(|this, _, _| this.success_alert()),
+        ]
     }
 }
 
 impl DialogComponentView {
-    /// Example code for the Dialog component
+    // Example code for the Dialog component
 
     fn basic_dialog(&self) -> AnyElement {
         Button::new("open-dialog-button")
@@ -2,31 +2,32 @@ use gpui::*;
 use gpui_component::button::{Button, ButtonVariants, DropdownButton};
 use gpui_component::*;
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
 
 pub struct DropdownButtonComponentView;
 
 impl ComponentMeta for DropdownButtonComponentView {
+    const NAME: &'static str = "Dropdown Button";
+    const CATEGORY: ComponentCategory = ComponentCategory::General;
     const DESCRIPTION: &'static str = "A DropdownButton is a combination of a button and a trigger button. \nIt allows us to display a dropdown menu when the trigger is clicked, \nbut the left Button can still respond to independent events. \n\nAnd more option methods of Button are also available for the DropdownButton, \nsuch as setting different variants using ButtonCustomVariant, sizes using Sizable, adding icons, loading states.";
     const LINK: &'static str =
         "https://longbridge.github.io/gpui-component/docs/components/dropdown_button";
-}
 
-impl Render for DropdownButtonComponentView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Basic Dropdown Button"))
-            .child(self.basic_dropdown_button())
-            .child(subtitle("Variants"))
-            .child(self.variants())
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_dropdown_button", "Basic Dropdown Button")
+                .render(|this, _, _| this.basic_dropdown_button()),
+            Self::example("variants", "Variants").render(|this, _, _| this.variants()),
+        ]
     }
 }
 
 impl DropdownButtonComponentView {
-    /// Example code for the DropdownButton component
+    // Example code for the DropdownButton component
 
     fn basic_dropdown_button(&self) -> AnyElement {
         DropdownButton::new("dropdown-basic")
@@ -3,56 +3,59 @@ use gpui_component::button::Button;
 use gpui_component::input::{Input, InputEvent, InputState, Position, TabSize};
 use gpui_component::*;
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+use crate::preferences::Preferences;
 
 pub struct EditorComponentView;
 
 impl ComponentMeta for EditorComponentView {
+    const NAME: &'static str = "Editor";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataEntry;
     const DESCRIPTION: &'static str = "A powerful multi-line text input component that extends the basic input functionality with support for multiple lines, \nauto-resizing, syntax highlighting, line numbers, and code editing features. \nPerfect for forms, code editors, and content editing.";
     const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/editor";
-}
 
-impl Render for EditorComponentView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Textarea"))
-            .child(self.textarea(_window, _cx))
-            .child(subtitle("AutoGrow"))
-            .child(self.autogrow(_window, _cx))
-            .child(subtitle("CodeEditor"))
-            .child(self.code_editor(_window, _cx))
-            .child(subtitle("Single Line Mode"))
-            .child(self.single_line_mode(_window, _cx))
-            .child(subtitle("TabSize"))
-            .child(self.tab_size(_window, _cx))
-            .child(subtitle("Searchable"))
-            .child(self.searchable(_window, _cx))
-            .child(subtitle("SoftWrap"))
-            .child(self.soft_wrap(_window, _cx))
-            .child(subtitle("Text Manipulation"))
-            .child(self.text_manipulation(_window, _cx))
-            .child(subtitle("Validation"))
-            .child(self.validation(_window, _cx))
-            .child(subtitle("Handle Events"))
-            .child(self.handle_events(_window, _cx))
-            .child(subtitle("Disabled State"))
-            .child(self.disabled_state(_window, _cx))
-            .child(subtitle("Custom Styling"))
-            .child(self.custom_styling(_window, _cx))
-            .child(subtitle("Comment Box"))
-            .child("Not implemented yet")
-            .child(subtitle("Code Editor with Language Selection"))
-            .child("Not implemented yet")
-            .child(subtitle("Text Editor with Toolbar"))
-            .child("Not implemented yet")
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("textarea", "Textarea")
+                .render(|this, window, cx| this.textarea(window, cx)),
+            Self::example("autogrow", "AutoGrow")
+                .render(|this, window, cx| this.autogrow(window, cx)),
+            Self::example("code_editor", "CodeEditor")
+                .render(|this, window, cx| this.code_editor(window, cx)),
+            Self::example("single_line_mode", "Single Line Mode")
+                .render(|this, window, cx| this.single_line_mode(window, cx)),
+            Self::example("tab_size", "TabSize")
+                .render(|this, window, cx| this.tab_size(window, cx)),
+            Self::example("searchable", "Searchable")
+                .render(|this, window, cx| this.searchable(window, cx)),
+            Self::example("soft_wrap", "SoftWrap")
+                .render(|this, window, cx| this.soft_wrap(window, cx)),
+            Self::example("text_manipulation", "Text Manipulation")
+                .render(|this, window, cx| this.text_manipulation(window, cx)),
+            Self::example("validation", "Validation")
+                .render(|this, window, cx| this.validation(window, cx)),
+            Self::example("handle_events", "Handle Events")
+                .render(|this, window, cx| this.handle_events(window, cx)),
+            Self::example("disabled_state", "Disabled State")
+                .render(|this, window, cx| this.disabled_state(window, cx)),
+            Self::example("custom_styling", "Custom Styling")
+                .render(|this, window, cx| this.custom_styling(window, cx)),
+            Self::example("comment_box", "Comment Box"),
+            Self::example(
+                "code_editor_with_language_selection",
+                "Code Editor with Language Selection",
+            ),
+            Self::example("text_editor_with_toolbar", "Text Editor with Toolbar"),
+        ]
     }
 }
 
 impl EditorComponentView {
-    /// Example code for the Editor component
+    // Example code for the Editor component
 
     fn textarea(&self, window: &mut Window, cx: &mut Context<Self>) -> AnyElement {
         let state = cx.new(|cx| {
@@ -82,6 +85,7 @@ impl EditorComponentView {
             InputState::new(window, cx)
                 .code_editor("rust") // Language for syntax highlighting
                 .line_number(true) // Show line numbers
+                .tab_size(Preferences::global(cx).tab_size()) // Set in the Settings page
                 .searchable(true) // Enable search functionality
                 .default_value("fn main() {\n    println!(\"Hello, world!\");\n}")
         });
@@ -103,11 +107,12 @@ impl EditorComponentView {
     }
 
     fn tab_size(&self, window: &mut Window, cx: &mut Context<Self>) -> AnyElement {
+        let tab_size = Preferences::global(cx).editor_tab_size; // Set in the Settings page
         let state = cx.new(|cx| {
             InputState::new(window, cx)
                 .multi_line(true)
                 .tab_size(TabSize {
-                    tab_size: 4,
+                    tab_size,
                     hard_tabs: false, // Use spaces instead of tabs
                 })
         });
@@ -219,13 +224,12 @@ impl EditorComponentView {
     }
 
     fn validation(&self, window: &mut Window, cx: &mut Context<Self>) -> AnyElement {
+        // Validation is only supported by single line inputs
         let state = cx.new(|cx| {
-            InputState::new(window, cx)
-                .multi_line(true)
-                .validate(|text, _| {
-                    // Validate that content is not empty and under 1000 chars
-                    !text.trim().is_empty() && text.len() <= 1000
-                })
+            InputState::new(window, cx).validate(|text, _| {
+                // Validate that content is not empty and under 1000 chars
+                !text.trim().is_empty() && text.len() <= 1000
+            })
         });
 
         Input::new(&state).into_any_element()
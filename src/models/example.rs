@@ -0,0 +1,107 @@
+use gpui::{AnyElement, Context, IntoElement, ParentElement, Styled, Window, div};
+use gpui_component::ActiveTheme;
+
+include!(concat!(env!("OUT_DIR"), "/example_sources.rs"));
+
+type RenderFn<V> = Box<dyn Fn(&mut V, &mut Window, &mut Context<V>) -> AnyElement>;
+
+/// A single example shown on a showcase page
+///
+/// The `id` is stable across releases and is used for deep links and for
+/// looking up the example source, so it usually matches the name of the
+/// function rendering the example.
+pub struct Example<V> {
+    id: &'static str,
+    title: &'static str,
+    description: Option<&'static str>,
+    source: Option<&'static str>,
+    full_width: bool,
+    render: Option<RenderFn<V>>,
+}
+
+impl<V: 'static> Example<V> {
+    pub fn new(id: &'static str, title: &'static str) -> Self {
+        Self {
+            id,
+            title,
+            description: None,
+            source: None,
+            full_width: false,
+            render: None,
+        }
+    }
+
+    pub fn description(mut self, description: &'static str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Override the source shown for this example, by default the source of
+    /// the view function named like the example id is used
+    pub fn source(mut self, source: &'static str) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Give the example the full width of the page, for wide components like
+    /// tables, instead of the default narrow column
+    pub fn full_width(mut self) -> Self {
+        self.full_width = true;
+        self
+    }
+
+    /// Set the render function, examples without one are flagged as not implemented
+    pub fn render(
+        mut self,
+        render: impl Fn(&mut V, &mut Window, &mut Context<V>) -> AnyElement + 'static,
+    ) -> Self {
+        self.render = Some(Box::new(render));
+        self
+    }
+
+    pub fn info(&self) -> ExampleInfo {
+        ExampleInfo {
+            id: self.id,
+            title: self.title,
+            description: self.description,
+            source: self.source.or_else(|| example_source::<V>(self.id)),
+            full_width: self.full_width,
+            implemented: self.render.is_some(),
+        }
+    }
+
+    pub fn render_element(
+        &self,
+        view: &mut V,
+        window: &mut Window,
+        cx: &mut Context<V>,
+    ) -> AnyElement {
+        match &self.render {
+            Some(render) => render(view, window, cx),
+            None => div()
+                .text_color(cx.theme().muted_foreground)
+                .child("Not implemented yet")
+                .into_any_element(),
+        }
+    }
+}
+
+/// Look up the source of the `id` function of the view `V`, extracted by `build.rs`
+fn example_source<V>(id: &str) -> Option<&'static str> {
+    let view = std::any::type_name::<V>().rsplit("::").next()?;
+    EXAMPLE_SOURCES
+        .iter()
+        .find(|(source_view, function, _)| *source_view == view && *function == id)
+        .map(|(_, _, source)| *source)
+}
+
+/// Metadata of an example without its render function
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExampleInfo {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub description: Option<&'static str>,
+    pub source: Option<&'static str>,
+    pub full_width: bool,
+    pub implemented: bool,
+}
@@ -1,72 +1,65 @@
 use gpui::*;
 use gpui_component::button::{Button, ButtonVariants};
-use gpui_component::form::{Field, Form, field, h_form, v_form};
+use gpui_component::form::{field, h_form, v_form};
 use gpui_component::input::{Input, InputState};
 use gpui_component::switch::Switch;
 use gpui_component::*;
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
 
 pub struct FormComponentView;
 
 impl ComponentMeta for FormComponentView {
+    const NAME: &'static str = "Form";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataEntry;
     const DESCRIPTION: &'static str = "A comprehensive form component that provides structured layout for form fields with support for vertical/horizontal layouts, \nvalidation, field groups, and responsive multi-column layouts.";
     const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/form";
-}
 
-impl Render for FormComponentView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Basic Form"))
-            .child(self.basic_form(_window, _cx))
-            .child(subtitle("Horizontal Form Layout"))
-            .child(self.horizontal_form_layout(_window, _cx))
-            .child(subtitle("Multi-Column Form"))
-            .child(self.multi_column_form(_window, _cx))
-            .child(subtitle("Vertical Layout (Default)"))
-            .child(self.vertical_layout(_window, _cx))
-            .child(subtitle("Horizontal Layout"))
-            .child(self.horizontal_layout(_window, _cx))
-            .child(subtitle("Custom Sizing"))
-            .child(self.custom_sizing(_window, _cx))
-            .child(subtitle("Required Fields"))
-            .child(self.required_fields(_window, _cx))
-            .child(subtitle("Field Descriptions"))
-            .child(self.field_descriptions(_window, _cx))
-            .child(subtitle("Dynamic Descriptions"))
-            .child(self.dynamic_descriptions(_window, _cx))
-            .child(subtitle("Field Visibility"))
-            .child(self.field_visibility())
-            .child(subtitle("Basic Submit Pattern"))
-            .child("Not implemented yet")
-            .child(subtitle("Form with Action Buttons"))
-            .child(self.form_with_action_buttons(_window, _cx))
-            .child(subtitle("Related Fields"))
-            .child(self.related_fields(_window, _cx))
-            .child(subtitle("Custom Field Components"))
-            .child("Not implemented yet")
-            .child(subtitle("Conditional Fields"))
-            .child("Not implemented yet")
-            .child(subtitle("Column Spanning"))
-            .child("Not implemented yet")
-            .child(subtitle("Column Positioning"))
-            .child("Not implemented yet")
-            .child(subtitle("Responsive Layout"))
-            .child("Not implemented yet")
-            .child(subtitle("User Registration Form"))
-            .child("Not implemented yet")
-            .child(subtitle("Settings Form with Sections"))
-            .child("Not implemented yet")
-            .child(subtitle("Contact Form"))
-            .child("Not implemented yet")
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_form", "Basic Form")
+                .render(|this, window, cx| this.basic_form(window, cx)),
+            Self::example("horizontal_form_layout", "Horizontal Form Layout")
+                .render(|this, window, cx| this.horizontal_form_layout(window, cx)),
+            Self::example("multi_column_form", "Multi-Column Form")
+                .render(|this, window, cx| this.multi_column_form(window, cx)),
+            Self::example("vertical_layout", "Vertical Layout (Default)")
+                .render(|this, window, cx| this.vertical_layout(window, cx)),
+            Self::example("horizontal_layout", "Horizontal Layout")
+                .render(|this, window, cx| this.horizontal_layout(window, cx)),
+            Self::example("custom_sizing", "Custom Sizing")
+                .render(|this, window, cx| this.custom_sizing(window, cx)),
+            Self::example("required_fields", "Required Fields")
+                .render(|this, window, cx| this.required_fields(window, cx)),
+            Self::example("field_descriptions", "Field Descriptions")
+                .render(|this, window, cx| this.field_descriptions(window, cx)),
+            Self::example("dynamic_descriptions", "Dynamic Descriptions")
+                .render(|this, window, cx| this.dynamic_descriptions(window, cx)),
+            Self::example("field_visibility", "Field Visibility")
+                .render(|this, _, _| this.field_visibility()),
+            Self::example("basic_submit_pattern", "Basic Submit Pattern"),
+            Self::example("form_with_action_buttons", "Form with Action Buttons")
+                .render(|this, window, cx| this.form_with_action_buttons(window, cx)),
+            Self::example("related_fields", "Related Fields")
+                .render(|this, window, cx| this.related_fields(window, cx)),
+            Self::example("custom_field_components", "Custom Field Components"),
+            Self::example("conditional_fields", "Conditional Fields"),
+            Self::example("column_spanning", "Column Spanning"),
+            Self::example("column_positioning", "Column Positioning"),
+            Self::example("responsive_layout", "Responsive Layout"),
+            Self::example("user_registration_form", "User Registration Form"),
+            Self::example("settings_form_with_sections", "Settings Form with Sections"),
+            Self::example("contact_form", "Contact Form"),
+        ]
     }
 }
 
 impl FormComponentView {
-    /// Example code for the Form component
+    // Example code for the Form component
 
     fn basic_form(&self, window: &mut Window, cx: &mut Context<Self>) -> AnyElement {
         let name_input = cx.new(|cx| InputState::new(window, cx).placeholder("Enter your name"));
@@ -0,0 +1,344 @@
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::clipboard::Clipboard;
+use gpui_component::input::{Input, InputEvent, InputState, MaskPattern};
+use gpui_component::*;
+
+use crate::models::clipboard_component::API_KEY;
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+
+const MAX_LENGTH: usize = 40;
+
+const UNICODE_TEXT: &str = "你好，世界 · こんにちは · 안녕하세요 · مرحبا 👋🏽";
+
+/// Input masks, the pattern characters are `9` for a digit, `A` for a letter
+/// and `#` for a letter or digit, anything else is a separator
+const MASKS: [(&str, &str); 3] = [
+    ("Phone", "(999) 999-9999"),
+    ("Credit card", "9999 9999 9999 9999"),
+    ("Date", "9999-99-99"),
+];
+
+pub struct InputComponentView {
+    basic: Entity<InputState>,
+    disabled: Entity<InputState>,
+    search: Entity<InputState>,
+    url: Entity<InputState>,
+    message: Entity<InputState>,
+    password: Entity<InputState>,
+    cleanable: Entity<InputState>,
+    masks: Vec<Entity<InputState>>,
+    bio: Entity<InputState>,
+    email: Entity<InputState>,
+    email_error: Option<SharedString>,
+    unicode: Entity<InputState>,
+    api_key: Entity<InputState>,
+    sent: Vec<SharedString>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ComponentMeta for InputComponentView {
+    const NAME: &'static str = "Input";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataEntry;
+    const DESCRIPTION: &'static str = "A single line text input, its text and options live in an InputState entity. \nIt supports prefix and suffix elements, password masking, a clear button, \ninput masks, validation of every edit and unicode text entered with an IME.";
+    const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/input";
+
+    fn create(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let basic = cx.new(|cx| InputState::new(window, cx).placeholder("Enter your name"));
+        let disabled = cx.new(|cx| InputState::new(window, cx).default_value("Disabled input"));
+        let search = cx.new(|cx| InputState::new(window, cx).placeholder("Search components…"));
+        let url = cx.new(|cx| InputState::new(window, cx).default_value("gpui-component"));
+        let message = cx.new(|cx| InputState::new(window, cx).placeholder("Write a message"));
+        let password = cx.new(|cx| {
+            InputState::new(window, cx)
+                .masked(true)
+                .default_value("correct horse battery staple")
+        });
+        let cleanable = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("Type, then clear")
+                .default_value("Clear me")
+        });
+        let masks = MASKS
+            .into_iter()
+            .map(|(_, pattern)| {
+                cx.new(|cx| InputState::new(window, cx).mask_pattern(MaskPattern::new(pattern)))
+            })
+            .collect::<Vec<_>>();
+        // Edits making the text longer than the limit are rejected
+        let bio = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("A short bio")
+                .validate(|text, _| text.chars().count() <= MAX_LENGTH)
+        });
+        let email = cx.new(|cx| InputState::new(window, cx).placeholder("jane@example.com"));
+        let unicode = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("Type with an IME or paste emoji")
+                .default_value(UNICODE_TEXT)
+        });
+        let api_key = cx.new(|cx| InputState::new(window, cx).default_value(API_KEY));
+
+        let mut _subscriptions = masks
+            .iter()
+            .chain([&bio, &unicode])
+            .map(|state| {
+                cx.subscribe(state, |_, _, event: &InputEvent, cx| {
+                    if let InputEvent::Change = event {
+                        cx.notify();
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        _subscriptions.push(cx.subscribe(&email, Self::on_email_event));
+        _subscriptions.push(cx.subscribe_in(&message, window, Self::on_message_event));
+
+        Self {
+            basic,
+            disabled,
+            search,
+            url,
+            message,
+            password,
+            cleanable,
+            masks,
+            bio,
+            email,
+            email_error: None,
+            unicode,
+            api_key,
+            sent: Vec::new(),
+            _subscriptions,
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_input", "Basic Input").render(|this, _, _| this.basic_input()),
+            Self::example("prefix_and_suffix", "Prefix and Suffix")
+                .render(|this, _, cx| this.prefix_and_suffix(cx)),
+            Self::example("password", "Password").render(|this, _, _| this.password()),
+            Self::example("cleanable", "Cleanable").render(|this, _, _| this.cleanable()),
+            Self::example("input_masks", "Input Masks").render(|this, _, cx| this.input_masks(cx)),
+            Self::example("max_length", "Max Length with Counter")
+                .render(|this, _, cx| this.max_length(cx)),
+            Self::example("validation", "Validation").render(|this, _, cx| this.validation(cx)),
+            Self::example("unicode_text", "Unicode and IME Text")
+                .render(|this, _, cx| this.unicode_text(cx)),
+            Self::example("read_only", "Read-only with Clipboard")
+                .render(|this, _, _| this.read_only()),
+        ]
+    }
+}
+
+impl InputComponentView {
+    // Example code for the Input component
+
+    fn basic_input(&self) -> AnyElement {
+        v_flex()
+            .gap_3()
+            .child(Input::new(&self.basic))
+            .child(Input::new(&self.disabled).disabled(true))
+            .into_any_element()
+    }
+
+    fn prefix_and_suffix(&self, cx: &mut Context<Self>) -> AnyElement {
+        v_flex()
+            .gap_3()
+            .child(Input::new(&self.search).prefix(Icon::new(IconName::Search).small()))
+            .child(
+                Input::new(&self.url)
+                    .prefix(
+                        div()
+                            .text_color(cx.theme().muted_foreground)
+                            .child("https://"),
+                    )
+                    .suffix(div().text_color(cx.theme().muted_foreground).child(".dev")),
+            )
+            .child(
+                Input::new(&self.message).suffix(
+                    Button::new("send")
+                        .icon(IconName::ArrowRight)
+                        .ghost()
+                        .xsmall()
+                        .on_click(cx.listener(|this, _, window, cx| this.send(window, cx))),
+                ),
+            )
+            .when(!self.sent.is_empty(), |this| {
+                this.child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(format!("Sent: {}", self.sent.join(" · "))),
+                )
+            })
+            .into_any_element()
+    }
+
+    fn on_message_event(
+        &mut self,
+        _: &Entity<InputState>,
+        event: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::PressEnter { .. } = event {
+            self.send(window, cx);
+        }
+    }
+
+    /// Send the message with the suffix button or Enter, and clear the input
+    fn send(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let message = self.message.read(cx).value().trim().to_string();
+        if message.is_empty() {
+            return;
+        }
+        self.sent.push(message.into());
+        self.message
+            .update(cx, |input, cx| input.set_value("", window, cx));
+        cx.notify();
+    }
+
+    fn password(&self) -> AnyElement {
+        Input::new(&self.password)
+            .prefix(Icon::new(IconName::Asterisk).small())
+            .mask_toggle()
+            .into_any_element()
+    }
+
+    fn cleanable(&self) -> AnyElement {
+        Input::new(&self.cleanable)
+            .cleanable(true)
+            .into_any_element()
+    }
+
+    fn input_masks(&self, cx: &mut Context<Self>) -> AnyElement {
+        v_flex()
+            .gap_3()
+            .children(
+                MASKS
+                    .into_iter()
+                    .zip(&self.masks)
+                    .map(|((label, pattern), state)| {
+                        let value = state.read(cx).value();
+                        let unmasked = state.read(cx).unmask_value();
+                        v_flex()
+                            .gap_1()
+                            .child(div().text_sm().child(format!("{} · {}", label, pattern)))
+                            .child(Input::new(state))
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(format!(
+                                        "Value: {:?} · Unmasked: {:?}",
+                                        value, unmasked
+                                    )),
+                            )
+                    }),
+            )
+            .into_any_element()
+    }
+
+    fn max_length(&self, cx: &mut Context<Self>) -> AnyElement {
+        let count = self.bio.read(cx).value().chars().count();
+        let color = if count == MAX_LENGTH {
+            cx.theme().warning
+        } else {
+            cx.theme().muted_foreground
+        };
+
+        Input::new(&self.bio)
+            .suffix(
+                div()
+                    .text_xs()
+                    .text_color(color)
+                    .child(format!("{}/{}", count, MAX_LENGTH)),
+            )
+            .into_any_element()
+    }
+
+    fn validation(&self, cx: &mut Context<Self>) -> AnyElement {
+        let value = self.email.read(cx).value();
+        let valid = !value.is_empty() && self.email_error.is_none();
+
+        v_flex()
+            .gap_1()
+            .child(
+                Input::new(&self.email)
+                    .prefix(Icon::new(IconName::Inbox).small())
+                    .when(self.email_error.is_some(), |this| {
+                        this.border_color(cx.theme().danger)
+                    })
+                    .when(valid, |this| {
+                        this.suffix(
+                            Icon::new(IconName::CircleCheck)
+                                .small()
+                                .text_color(cx.theme().success),
+                        )
+                    }),
+            )
+            .child(div().text_xs().map(|this| {
+                match &self.email_error {
+                    Some(error) => this.text_color(cx.theme().danger).child(error.clone()),
+                    None if valid => this.text_color(cx.theme().success).child("Looks good."),
+                    None => this
+                        .text_color(cx.theme().muted_foreground)
+                        .child("We never share your email."),
+                }
+            }))
+            .into_any_element()
+    }
+
+    /// Validate the email on every change, an empty input is not an error yet
+    fn on_email_event(
+        &mut self,
+        state: Entity<InputState>,
+        event: &InputEvent,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change = event {
+            let value = state.read(cx).value();
+            self.email_error = match value.split_once('@') {
+                _ if value.is_empty() => None,
+                Some((user, domain)) if !user.is_empty() && domain.contains('.') => None,
+                Some(_) => Some("The domain is incomplete, e.g. example.com".into()),
+                None => Some("An email address contains an @".into()),
+            };
+            cx.notify();
+        }
+    }
+
+    fn unicode_text(&self, cx: &mut Context<Self>) -> AnyElement {
+        let value = self.unicode.read(cx).value();
+
+        v_flex()
+            .gap_1()
+            .child(Input::new(&self.unicode).cleanable(true))
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format!(
+                        "{} characters · {} bytes",
+                        value.chars().count(),
+                        value.len()
+                    )),
+            )
+            .into_any_element()
+    }
+
+    fn read_only(&self) -> AnyElement {
+        // A disabled input can't be edited, but its text can still be selected
+        // and copied
+        Input::new(&self.api_key)
+            .disabled(true)
+            .suffix(
+                Clipboard::new("api-key-copy")
+                    .value(API_KEY)
+                    .on_copied(|_, window, cx| window.push_notification("API key copied!", cx)),
+            )
+            .into_any_element()
+    }
+}
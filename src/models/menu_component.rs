@@ -0,0 +1,500 @@
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::Button;
+use gpui_component::menu::{ContextMenuExt, DropdownMenu, PopupMenu};
+use gpui_component::*;
+use serde::Deserialize;
+
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+
+/// Key context of the menu demos, the shortcuts only apply while one of them
+/// is focused
+const CONTEXT: &str = "MenuDemo";
+
+const SAMPLE_TEXT: &str = "The quick brown fox jumps over the lazy dog.";
+
+const DEFAULT_FONT_SIZE: f32 = 16.;
+const MIN_FONT_SIZE: f32 = 10.;
+const MAX_FONT_SIZE: f32 = 32.;
+const MAX_LINES: usize = 5;
+
+actions!(
+    menu_demo,
+    [
+        ToggleBold,
+        ToggleItalic,
+        ToggleUnderline,
+        ZoomIn,
+        ZoomOut,
+        ResetZoom,
+        Undo,
+        Redo,
+        DuplicateLine,
+        DeleteLine,
+        CopyText,
+        ReplaceText,
+    ]
+);
+
+#[derive(Action, Clone, PartialEq, Deserialize)]
+#[action(namespace = menu_demo, no_json)]
+struct SetAlignment(Alignment);
+
+#[derive(Action, Clone, PartialEq, Deserialize)]
+#[action(namespace = menu_demo, no_json)]
+struct SetTextColor(TextColor);
+
+/// Register the shortcuts shown next to the menu items
+pub fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("secondary-b", ToggleBold, Some(CONTEXT)),
+        KeyBinding::new("secondary-i", ToggleItalic, Some(CONTEXT)),
+        KeyBinding::new("secondary-u", ToggleUnderline, Some(CONTEXT)),
+        KeyBinding::new("secondary-=", ZoomIn, Some(CONTEXT)),
+        KeyBinding::new("secondary--", ZoomOut, Some(CONTEXT)),
+        KeyBinding::new("secondary-0", ResetZoom, Some(CONTEXT)),
+        KeyBinding::new("secondary-z", Undo, Some(CONTEXT)),
+        KeyBinding::new("secondary-shift-z", Redo, Some(CONTEXT)),
+        KeyBinding::new("secondary-d", DuplicateLine, Some(CONTEXT)),
+        KeyBinding::new("secondary-backspace", DeleteLine, Some(CONTEXT)),
+        KeyBinding::new("secondary-c", CopyText, Some(CONTEXT)),
+        KeyBinding::new("secondary-alt-f", ReplaceText, Some(CONTEXT)),
+    ]);
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Deserialize)]
+enum Alignment {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+impl Alignment {
+    const ALL: [Self; 3] = [Self::Left, Self::Center, Self::Right];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Left => "Left",
+            Self::Center => "Center",
+            Self::Right => "Right",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Deserialize)]
+enum TextColor {
+    #[default]
+    Default,
+    Primary,
+    Muted,
+    Red,
+    Green,
+    Blue,
+}
+
+impl TextColor {
+    const THEME: [Self; 3] = [Self::Default, Self::Primary, Self::Muted];
+    const PALETTE: [Self; 3] = [Self::Red, Self::Green, Self::Blue];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Primary => "Primary",
+            Self::Muted => "Muted",
+            Self::Red => "Red",
+            Self::Green => "Green",
+            Self::Blue => "Blue",
+        }
+    }
+
+    fn color(self, cx: &App) -> Hsla {
+        let theme = cx.theme();
+        match self {
+            Self::Default => theme.foreground,
+            Self::Primary => theme.primary,
+            Self::Muted => theme.muted_foreground,
+            Self::Red => theme.red,
+            Self::Green => theme.green,
+            Self::Blue => theme.blue,
+        }
+    }
+}
+
+/// Style of the sample text, every change pushes the previous one to the
+/// undo history
+#[derive(Clone, Copy, PartialEq)]
+struct TextStyle {
+    bold: bool,
+    italic: bool,
+    underline: bool,
+    font_size: f32,
+    alignment: Alignment,
+    color: TextColor,
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        Self {
+            bold: false,
+            italic: false,
+            underline: false,
+            font_size: DEFAULT_FONT_SIZE,
+            alignment: Alignment::default(),
+            color: TextColor::default(),
+        }
+    }
+}
+
+pub struct MenuComponentView {
+    style: TextStyle,
+    history: Vec<TextStyle>,
+    lines: usize,
+    last_action: Option<SharedString>,
+    /// Focus of the dropdown menu demo, its menus dispatch their actions here
+    dropdown_focus: FocusHandle,
+    /// Focus of the context menu demo
+    context_focus: FocusHandle,
+}
+
+impl ComponentMeta for MenuComponentView {
+    const NAME: &'static str = "Menu";
+    const CATEGORY: ComponentCategory = ComponentCategory::Overlay;
+    const DESCRIPTION: &'static str = "Popup menus opened from a button or by right-clicking an element. \nMenu items dispatch actions, can be checked, disabled, have icons and nested submenus, \nand show the keyboard shortcuts bound to their actions.";
+    const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/menu";
+
+    fn create(_window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            style: TextStyle::default(),
+            history: Vec::new(),
+            lines: 1,
+            last_action: None,
+            dropdown_focus: cx.focus_handle(),
+            context_focus: cx.focus_handle(),
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("dropdown_menu", "Dropdown Menu")
+                .render(|this, _, cx| this.dropdown_menu(cx)),
+            Self::example("context_menu", "Context Menu")
+                .render(|this, _, cx| this.context_menu(cx)),
+            Self::example("icons_and_disabled_items", "Icons and Disabled Items")
+                .render(|this, _, cx| this.icons_and_disabled_items(cx)),
+        ]
+    }
+}
+
+impl MenuComponentView {
+    // Example code for the Menu component
+
+    fn dropdown_menu(&self, cx: &mut Context<Self>) -> AnyElement {
+        let style = self.style;
+        let focus = self.dropdown_focus.clone();
+
+        self.demo_area("menu-dropdown-demo", &self.dropdown_focus, cx)
+            .child(Button::new("menu-format").label("Format").dropdown_menu(
+                move |menu, window, cx| {
+                    menu.action_context(focus.clone())
+                        .menu_with_check("Bold", style.bold, Box::new(ToggleBold))
+                        .menu_with_check("Italic", style.italic, Box::new(ToggleItalic))
+                        .menu_with_check("Underline", style.underline, Box::new(ToggleUnderline))
+                        .separator()
+                        .menu_with_icon("Zoom In", IconName::Plus, Box::new(ZoomIn))
+                        .menu_with_icon("Zoom Out", IconName::Minus, Box::new(ZoomOut))
+                        .menu("Reset Zoom", Box::new(ResetZoom))
+                        .separator()
+                        .submenu("Alignment", window, cx, move |menu, _, _| {
+                            alignment_menu(menu, style.alignment)
+                        })
+                        .submenu_with_icon(
+                            Some(IconName::Palette.into()),
+                            "Text Color",
+                            window,
+                            cx,
+                            move |menu, window, cx| text_color_menu(menu, style.color, window, cx),
+                        )
+                },
+            ))
+            .child(self.preview(cx))
+            .into_any_element()
+    }
+
+    fn context_menu(&self, cx: &mut Context<Self>) -> AnyElement {
+        let style = self.style;
+        let undo_disabled = self.history.is_empty();
+        let delete_disabled = self.lines <= 1;
+        let duplicate_disabled = self.lines >= MAX_LINES;
+        let focus = self.context_focus.clone();
+
+        self.demo_area("menu-context-demo", &self.context_focus, cx)
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child("Right-click the preview to open its context menu."),
+            )
+            .child(self.preview(cx).context_menu(move |menu, window, cx| {
+                menu.action_context(focus.clone())
+                    .menu_with_icon_and_disabled(
+                        "Undo",
+                        IconName::Undo,
+                        Box::new(Undo),
+                        undo_disabled,
+                    )
+                    .separator()
+                    .menu_with_icon("Copy Text", IconName::Copy, Box::new(CopyText))
+                    .menu_with_disabled(
+                        "Duplicate Line",
+                        Box::new(DuplicateLine),
+                        duplicate_disabled,
+                    )
+                    .menu_with_icon_and_disabled(
+                        "Delete Line",
+                        IconName::Delete,
+                        Box::new(DeleteLine),
+                        delete_disabled,
+                    )
+                    .separator()
+                    .submenu("Style", window, cx, move |menu, window, cx| {
+                        menu.menu_with_check("Bold", style.bold, Box::new(ToggleBold))
+                            .menu_with_check("Italic", style.italic, Box::new(ToggleItalic))
+                            .menu_with_check(
+                                "Underline",
+                                style.underline,
+                                Box::new(ToggleUnderline),
+                            )
+                            .separator()
+                            .submenu("Alignment", window, cx, move |menu, _, _| {
+                                alignment_menu(menu, style.alignment)
+                            })
+                            .submenu("Text Color", window, cx, move |menu, window, cx| {
+                                text_color_menu(menu, style.color, window, cx)
+                            })
+                    })
+            }))
+            .into_any_element()
+    }
+
+    fn icons_and_disabled_items(&self, cx: &mut Context<Self>) -> AnyElement {
+        let undo_disabled = self.history.is_empty();
+        let focus = self.dropdown_focus.clone();
+
+        h_flex()
+            .gap_2()
+            .child(
+                Button::new("menu-edit")
+                    .label("Edit")
+                    .icon(IconName::ChevronDown)
+                    .dropdown_menu(move |menu, _, _| {
+                        menu.action_context(focus.clone())
+                            .label("History")
+                            .menu_with_icon_and_disabled(
+                                "Undo",
+                                IconName::Undo,
+                                Box::new(Undo),
+                                undo_disabled,
+                            )
+                            // Redo and Replace have no handler, they only show a
+                            // disabled item with its shortcut
+                            .menu_with_icon_and_disabled(
+                                "Redo",
+                                IconName::Redo,
+                                Box::new(Redo),
+                                true,
+                            )
+                            .separator()
+                            .label("Clipboard")
+                            .menu_with_icon("Copy Text", IconName::Copy, Box::new(CopyText))
+                            .menu_with_icon_and_disabled(
+                                "Replace…",
+                                IconName::Replace,
+                                Box::new(ReplaceText),
+                                true,
+                            )
+                            .separator()
+                            .link_with_icon("Documentation", IconName::BookOpen, Self::LINK)
+                    }),
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(if undo_disabled {
+                        "Undo is enabled once the text style is changed."
+                    } else {
+                        "Undo reverts the last change to the text style."
+                    }),
+            )
+            .into_any_element()
+    }
+
+    /// A focusable area handling the actions of the menus, so the menus
+    /// and their shortcuts change the page
+    fn demo_area(
+        &self,
+        id: &'static str,
+        focus_handle: &FocusHandle,
+        cx: &mut Context<Self>,
+    ) -> Stateful<Div> {
+        let focus = focus_handle.clone();
+        v_flex()
+            .id(id)
+            .gap_3()
+            .w_full()
+            .key_context(CONTEXT)
+            .track_focus(focus_handle)
+            .on_mouse_down(MouseButton::Left, move |_, window, _| focus.focus(window))
+            .on_action(cx.listener(|this, _: &ToggleBold, _, cx| {
+                this.update_style("Bold", cx, |style| style.bold = !style.bold);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleItalic, _, cx| {
+                this.update_style("Italic", cx, |style| style.italic = !style.italic);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleUnderline, _, cx| {
+                this.update_style("Underline", cx, |style| style.underline = !style.underline);
+            }))
+            .on_action(cx.listener(|this, _: &ZoomIn, _, cx| {
+                this.update_style("Zoom In", cx, |style| {
+                    style.font_size = (style.font_size + 2.).min(MAX_FONT_SIZE);
+                });
+            }))
+            .on_action(cx.listener(|this, _: &ZoomOut, _, cx| {
+                this.update_style("Zoom Out", cx, |style| {
+                    style.font_size = (style.font_size - 2.).max(MIN_FONT_SIZE);
+                });
+            }))
+            .on_action(cx.listener(|this, _: &ResetZoom, _, cx| {
+                this.update_style("Reset Zoom", cx, |style| {
+                    style.font_size = DEFAULT_FONT_SIZE;
+                });
+            }))
+            .on_action(cx.listener(|this, action: &SetAlignment, _, cx| {
+                let alignment = action.0;
+                this.update_style(format!("Align {}", alignment.label()), cx, |style| {
+                    style.alignment = alignment;
+                });
+            }))
+            .on_action(cx.listener(|this, action: &SetTextColor, _, cx| {
+                let color = action.0;
+                this.update_style(format!("{} Color", color.label()), cx, |style| {
+                    style.color = color;
+                });
+            }))
+            .on_action(cx.listener(|this, _: &Undo, _, cx| {
+                if let Some(style) = this.history.pop() {
+                    this.style = style;
+                    this.last_action = Some("Undo".into());
+                    cx.notify();
+                }
+            }))
+            .on_action(cx.listener(|this, _: &DuplicateLine, _, cx| {
+                if this.lines < MAX_LINES {
+                    this.lines += 1;
+                    this.last_action = Some("Duplicate Line".into());
+                    cx.notify();
+                }
+            }))
+            .on_action(cx.listener(|this, _: &DeleteLine, _, cx| {
+                if this.lines > 1 {
+                    this.lines -= 1;
+                    this.last_action = Some("Delete Line".into());
+                    cx.notify();
+                }
+            }))
+            .on_action(cx.listener(|this, _: &CopyText, _, cx| {
+                let text = vec![SAMPLE_TEXT; this.lines].join("\n");
+                cx.write_to_clipboard(ClipboardItem::new_string(text));
+                this.last_action = Some("Copy Text".into());
+                cx.notify();
+            }))
+    }
+
+    /// Apply a change to the text style, keeping the previous style for undo
+    fn update_style(
+        &mut self,
+        action: impl Into<SharedString>,
+        cx: &mut Context<Self>,
+        f: impl FnOnce(&mut TextStyle),
+    ) {
+        let mut style = self.style;
+        f(&mut style);
+        if style != self.style {
+            self.history.push(self.style);
+            self.style = style;
+        }
+        self.last_action = Some(action.into());
+        cx.notify();
+    }
+
+    /// The sample text in the current style, with the last dispatched action
+    fn preview(&self, cx: &App) -> Div {
+        let style = self.style;
+        let muted = cx.theme().muted_foreground;
+
+        v_flex()
+            .gap_2()
+            .p_4()
+            .w_full()
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(cx.theme().radius)
+            .children((0..self.lines).map(|_| {
+                div()
+                    .w_full()
+                    .text_size(px(style.font_size))
+                    .text_color(style.color.color(cx))
+                    .when(style.bold, |this| this.font_weight(FontWeight::BOLD))
+                    .when(style.italic, |this| this.italic())
+                    .when(style.underline, |this| this.underline())
+                    .map(|this| match style.alignment {
+                        Alignment::Left => this.text_left(),
+                        Alignment::Center => this.text_center(),
+                        Alignment::Right => this.text_right(),
+                    })
+                    .child(SAMPLE_TEXT)
+            }))
+            .child(div().text_xs().text_color(muted).child(format!(
+                "Font size: {} px · Undo history: {} · Last action: {}",
+                style.font_size,
+                self.history.len(),
+                self.last_action.as_ref().map_or("None", |action| action.as_ref())
+            )))
+    }
+}
+
+/// Radio-like items choosing the text alignment
+fn alignment_menu(menu: PopupMenu, current: Alignment) -> PopupMenu {
+    Alignment::ALL.into_iter().fold(menu, |menu, alignment| {
+        menu.menu_with_check(
+            alignment.label(),
+            alignment == current,
+            Box::new(SetAlignment(alignment)),
+        )
+    })
+}
+
+/// Text colors grouped in nested submenus, the theme colors and a palette
+fn text_color_menu(
+    menu: PopupMenu,
+    current: TextColor,
+    window: &mut Window,
+    cx: &mut Context<PopupMenu>,
+) -> PopupMenu {
+    let colors = move |menu: PopupMenu, colors: [TextColor; 3]| {
+        colors.into_iter().fold(menu, |menu, color| {
+            menu.menu_with_check(
+                color.label(),
+                color == current,
+                Box::new(SetTextColor(color)),
+            )
+        })
+    };
+    menu.submenu("Theme", window, cx, move |menu, _, _| {
+        colors(menu, TextColor::THEME)
+    })
+    .submenu("Palette", window, cx, move |menu, _, _| {
+        colors(menu, TextColor::PALETTE)
+    })
+}
@@ -1,103 +1,78 @@
-pub mod accordion_component;
-pub mod alert_component;
-pub mod avatar_component;
-pub mod badge_component;
-pub mod button_component;
-pub mod calendar_component;
-pub mod chart_component;
-pub mod checkbox_component;
-pub mod clipboard_component;
-pub mod collapsible_component;
-pub mod color_picker_component;
-pub mod date_picker_component;
-pub mod description_list_component;
-pub mod dialog_component;
-pub mod dropdown_button_component;
-pub mod editor_component;
-pub mod form_component;
-// pub mod group_box_component;
-// pub mod icon_component;
-// pub mod image_component;
-// pub mod input_component;
-// pub mod kbd_component;
-// pub mod label_component;
-// pub mod list_component;
-// pub mod menu_component;
-// pub mod notification_component;
-// pub mod number_input_component;
-// pub mod opt_input_component;
-// pub mod plot_component;
-pub mod popover_component;
-// pub mod progress_component;
-// pub mod radio_component;
-// pub mod resizable_component;
-// pub mod select_component;
-// pub mod settings_component;
-// pub mod sheet_component;
-// pub mod side_component;
-pub mod skeleton_component;
-// pub mod slider_component;
-// pub mod spinner_component;
-// pub mod stepper_component;
-// pub mod switch_component;
-// pub mod table_component;
-// pub mod tabs_component;
-// pub mod tag_component;
-// pub mod title_bar_component;
-// pub mod toggle_component;
-// pub mod tooltip_component;
-// pub mod tree_component;
-// pub mod virtual_list_component;
+//! Showcase pages, one `*_component` module per page, and the registry
+//! listing them.
+//!
+//! Adding a page takes a single line in `register_components!` below, which
+//! declares the module, re-exports it and adds the page to the sidebar.
+
 pub mod components;
+pub mod example;
+pub mod registry;
+pub mod search;
+pub mod simulated_job;
 
-pub use accordion_component::*;
-pub use alert_component::*;
-pub use avatar_component::*;
-pub use badge_component::*;
-pub use button_component::*;
-pub use calendar_component::*;
-pub use chart_component::*;
-pub use checkbox_component::*;
-pub use clipboard_component::*;
-pub use collapsible_component::*;
-pub use color_picker_component::*;
-pub use date_picker_component::*;
-pub use description_list_component::*;
-pub use dialog_component::*;
-pub use dropdown_button_component::*;
-pub use editor_component::*;
-pub use form_component::*;
-// pub use group_box_component::*;
-// pub use icon_component::*;
-// pub use image_component::*;
-// pub use input_component::*;
-// pub use kbd_component::*;
-// pub use label_component::*;
-// pub use list_component::*;
-// pub use menu_component::*;
-// pub use notification_component::*;
-// pub use number_input_component::*;
-// pub use opt_input_component::*;
-// pub use plot_component::*;
-pub use popover_component::*;
-// pub use progress_component::*;
-// pub use radio_component::*;
-// pub use resizable_component::*;
-// pub use select_component::*;
-// pub use settings_component::*;
-// pub use sheet_component::*;
-// pub use side_component::*;
-pub use skeleton_component::*;
-// pub use slider_component::*;
-// pub use spinner_component::*;
-// pub use stepper_component::*;
-// pub use switch_component::*;
-// pub use table_component::*;
-// pub use tabs_component::*;
-// pub use tag_component::*;
-// pub use title_bar_component::*;
-// pub use toggle_component::*;
-// pub use tooltip_component::*;
-// pub use tree_component::*;
-// pub use virtual_list_component::*;
 pub use components::*;
+pub use example::{Example, ExampleInfo};
+pub use registry::{ComponentCategory, ComponentEntry, ComponentMeta};
+pub use search::{SearchMatch, search};
+
+use gpui::App;
+use registry::register_components;
+
+register_components! {
+    Accordion => accordion_component::AccordionComponentView,
+    Alert => alert_component::AlertComponentView,
+    Avatar => avatar_component::AvatarComponentView,
+    Badge => badge_component::BadgeComponentView,
+    Button => button_component::ButtonComponentView,
+    Calendar => calendar_component::CalendarComponentView,
+    Chart => chart_component::ChartComponentView,
+    Checkbox => checkbox_component::CheckboxComponentView,
+    Clipboard => clipboard_component::ClipboardComponentView,
+    Collapsible => collapsible_component::CollapsibleComponentView,
+    ColorPicker => color_picker_component::ColorPickerComponentView,
+    DatePicker => date_picker_component::DatePickerComponentView,
+    DescriptionList => description_list_component::DescriptionListComponentView,
+    Dialog => dialog_component::DialogComponentView,
+    DropdownButton => dropdown_button_component::DropdownButtonComponentView,
+    Editor => editor_component::EditorComponentView,
+    Form => form_component::FormComponentView,
+    // GroupBox => group_box_component::GroupBoxComponentView,
+    // Icon => icon_component::IconComponentView,
+    // Image => image_component::ImageComponentView,
+    Input => input_component::InputComponentView,
+    // Kbd => kbd_component::KbdComponentView,
+    // Label => label_component::LabelComponentView,
+    // List => list_component::ListComponentView,
+    Menu => menu_component::MenuComponentView,
+    Notification => notification_component::NotificationComponentView,
+    NumberInput => number_input_component::NumberInputComponentView,
+    OptInput => opt_input_component::OptInputComponentView,
+    // Plot => plot_component::PlotComponentView,
+    Popover => popover_component::PopoverComponentView,
+    Progress => progress_component::ProgressComponentView,
+    Radio => radio_component::RadioComponentView,
+    Resizable => resizable_component::ResizableComponentView,
+    Select => select_component::SelectComponentView,
+    Settings => settings_component::SettingsComponentView,
+    Sheet => sheet_component::SheetComponentView,
+    // Side => side_component::SideComponentView,
+    Skeleton => skeleton_component::SkeletonComponentView,
+    Slider => slider_component::SliderComponentView,
+    Spinner => spinner_component::SpinnerComponentView,
+    Stepper => stepper_component::StepperComponentView,
+    Switch => switch_component::SwitchComponentView,
+    Table => table_component::TableComponentView,
+    Tabs => tabs_component::TabsComponentView,
+    Tag => tag_component::TagComponentView,
+    // TitleBar => title_bar_component::TitleBarComponentView,
+    Toggle => toggle_component::ToggleComponentView,
+    // Tooltip => tooltip_component::TooltipComponentView,
+    Tree => tree_component::TreeComponentView,
+    VirtualList => virtual_list_component::VirtualListComponentView,
+}
+
+/// Register the key bindings of the showcase pages
+pub fn init(cx: &mut App) {
+    menu_component::init(cx);
+    opt_input_component::init(cx);
+}
@@ -0,0 +1,438 @@
+use std::time::Duration;
+
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::notification::{Notification, NotificationType};
+use gpui_component::progress::Progress;
+use gpui_component::*;
+
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+
+const TYPES: [(NotificationType, &str, &str); 4] = [
+    (
+        NotificationType::Info,
+        "Info",
+        "A new version is available.",
+    ),
+    (
+        NotificationType::Success,
+        "Success",
+        "Your changes have been saved.",
+    ),
+    (
+        NotificationType::Warning,
+        "Warning",
+        "Your session expires in 5 minutes.",
+    ),
+    (
+        NotificationType::Error,
+        "Error",
+        "Failed to connect to the server.",
+    ),
+];
+
+const BURST_SIZE: usize = 50;
+
+const INBOX: [&str; 5] = [
+    "Weekly report",
+    "Release notes draft",
+    "Design review",
+    "Invoice #1042",
+    "Team offsite",
+];
+
+/// Id of the upload notification, pushing it again replaces the previous one
+struct UploadNotification;
+
+/// Id of the sticky notification dismissed from the page
+struct StickyNotification;
+
+pub struct NotificationComponentView {
+    inbox: Vec<SharedString>,
+    archived: Vec<SharedString>,
+    upload_progress: Option<f32>,
+    _upload_task: Option<Task<()>>,
+}
+
+impl ComponentMeta for NotificationComponentView {
+    const NAME: &'static str = "Notification";
+    const CATEGORY: ComponentCategory = ComponentCategory::Feedback;
+    const DESCRIPTION: &'static str = "Notifications are pushed to the window with `window.push_notification` and stacked in its top right corner. \nThey have a type, an optional title, custom content and an action button, \nhide themselves after 5 seconds unless they are sticky, and can be dismissed by id.";
+    const LINK: &'static str =
+        "https://longbridge.github.io/gpui-component/docs/components/notification";
+
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self {
+            inbox: INBOX.into_iter().map(SharedString::from).collect(),
+            archived: Vec::new(),
+            upload_progress: None,
+            _upload_task: None,
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("notification_types", "Notification Types")
+                .render(|this, _, _| this.notification_types()),
+            Self::example("with_title", "With Title").render(|this, _, _| this.with_title()),
+            Self::example("custom_content", "Custom Content")
+                .render(|this, _, _| this.custom_content()),
+            Self::example("action_button", "Action Button")
+                .render(|this, _, cx| this.action_button(cx)),
+            Self::example("autohide_and_sticky", "Autohide and Sticky")
+                .render(|this, _, _| this.autohide_and_sticky()),
+            Self::example("dismiss_by_id", "Dismiss by Id")
+                .render(|this, _, cx| this.dismiss_by_id(cx)),
+            Self::example("burst", "Burst of 50 Notifications").render(|this, _, _| this.burst()),
+        ]
+    }
+}
+
+impl NotificationComponentView {
+    // Example code for the Notification component
+
+    fn notification_types(&self) -> AnyElement {
+        h_flex()
+            .gap_2()
+            .flex_wrap()
+            .children(TYPES.into_iter().map(|(type_, label, message)| {
+                Button::new(label)
+                    .label(label)
+                    .on_click(move |_, window, cx| {
+                        window.push_notification(
+                            Notification::new().message(message).with_type(type_),
+                            cx,
+                        );
+                    })
+            }))
+            .into_any_element()
+    }
+
+    fn with_title(&self) -> AnyElement {
+        h_flex()
+            .gap_2()
+            .child(
+                Button::new("title-success")
+                    .label("Saved")
+                    .on_click(|_, window, cx| {
+                        window.push_notification(
+                            Notification::success("All changes were written to disk.")
+                                .title("Document saved"),
+                            cx,
+                        );
+                    }),
+            )
+            .child(
+                Button::new("title-custom-icon")
+                    .label("Custom Icon")
+                    .on_click(|_, window, cx| {
+                        window.push_notification(
+                            Notification::new()
+                                .title("New follower")
+                                .message("Someone starred gpui-component.")
+                                .icon(IconName::Star),
+                            cx,
+                        );
+                    }),
+            )
+            .into_any_element()
+    }
+
+    fn custom_content(&self) -> AnyElement {
+        Button::new("custom-content")
+            .label("Build Summary")
+            .on_click(|_, window, cx| {
+                window.push_notification(
+                    Notification::new()
+                        .title("Build finished")
+                        .icon(IconName::SquareTerminal)
+                        .content(|_, _, cx| {
+                            let stat = |label: &'static str, value: &'static str, color: Hsla| {
+                                v_flex()
+                                    .flex_1()
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(label),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_lg()
+                                            .font_semibold()
+                                            .text_color(color)
+                                            .child(value),
+                                    )
+                            };
+                            h_flex()
+                                .mt_2()
+                                .gap_4()
+                                .child(stat("Crates", "212", cx.theme().foreground))
+                                .child(stat("Warnings", "0", cx.theme().success))
+                                .child(stat("Time", "38.2 s", cx.theme().foreground))
+                                .into_any_element()
+                        }),
+                    cx,
+                );
+            })
+            .into_any_element()
+    }
+
+    fn action_button(&self, cx: &mut Context<Self>) -> AnyElement {
+        v_flex()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("archive")
+                            .label("Archive First")
+                            .disabled(self.inbox.is_empty())
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.archive(window, cx);
+                            })),
+                    )
+                    .child(Button::new("reset-inbox").label("Reset").ghost().on_click(
+                        cx.listener(|this, _, _, cx| {
+                            this.inbox = INBOX.into_iter().map(SharedString::from).collect();
+                            this.archived.clear();
+                            cx.notify();
+                        }),
+                    )),
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format!(
+                        "Inbox: {} · Archived: {}",
+                        if self.inbox.is_empty() {
+                            "empty".to_string()
+                        } else {
+                            self.inbox.join(", ")
+                        },
+                        self.archived.len()
+                    )),
+            )
+            .into_any_element()
+    }
+
+    /// Archive the first message, the notification has an Undo button
+    /// putting it back
+    fn archive(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.inbox.is_empty() {
+            return;
+        }
+        let message = self.inbox.remove(0);
+        self.archived.push(message.clone());
+        cx.notify();
+
+        let view = cx.entity().downgrade();
+        window.push_notification(
+            Notification::new()
+                .message(format!("\"{}\" archived.", message))
+                .action(move |_, _, cx| {
+                    let view = view.clone();
+                    let message = message.clone();
+                    Button::new("undo")
+                        .label("Undo")
+                        .primary()
+                        .on_click(cx.listener(move |note, _, window, cx| {
+                            _ = view.update(cx, |view, cx| {
+                                view.archived.retain(|archived| archived != &message);
+                                view.inbox.insert(0, message.clone());
+                                cx.notify();
+                            });
+                            note.dismiss(window, cx);
+                        }))
+                }),
+            cx,
+        );
+    }
+
+    fn autohide_and_sticky(&self) -> AnyElement {
+        h_flex()
+            .gap_2()
+            .child(
+                Button::new("autohide")
+                    .label("Autohide")
+                    .on_click(|_, window, cx| {
+                        window.push_notification(
+                            Notification::info("This notification hides itself after 5 seconds."),
+                            cx,
+                        );
+                    }),
+            )
+            .child(
+                Button::new("sticky")
+                    .label("Sticky")
+                    .on_click(|_, window, cx| {
+                        window.push_notification(
+                            Notification::warning("This notification stays until it is closed.")
+                                .id::<StickyNotification>()
+                                .autohide(false),
+                            cx,
+                        );
+                    }),
+            )
+            .child(
+                Button::new("dismiss-sticky")
+                    .label("Dismiss Sticky")
+                    .ghost()
+                    .on_click(|_, window, cx| {
+                        window.remove_notification::<StickyNotification>(cx);
+                    }),
+            )
+            .into_any_element()
+    }
+
+    fn dismiss_by_id(&self, cx: &mut Context<Self>) -> AnyElement {
+        let uploading = self.upload_progress.is_some();
+
+        v_flex()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("start-upload")
+                            .label("Start Upload")
+                            .disabled(uploading)
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.start_upload(window, cx);
+                            })),
+                    )
+                    .child(
+                        Button::new("cancel-upload")
+                            .label("Cancel Upload")
+                            .ghost()
+                            .disabled(!uploading)
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.cancel_upload(window, cx);
+                            })),
+                    ),
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(match self.upload_progress {
+                        Some(progress) => format!("Uploading… {:.0}%", progress),
+                        None => "No upload in progress.".to_string(),
+                    }),
+            )
+            .into_any_element()
+    }
+
+    /// Push a sticky notification following the upload progress, it is
+    /// replaced by a success notification with the same id once done
+    fn start_upload(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.upload_progress = Some(0.);
+
+        let view = cx.entity().downgrade();
+        let cancel_view = view.clone();
+        window.push_notification(
+            Notification::new()
+                .id::<UploadNotification>()
+                .title("Uploading report.pdf")
+                .icon(IconName::ArrowUp)
+                .autohide(false)
+                .content(move |_, _, cx| {
+                    let progress = view
+                        .upgrade()
+                        .and_then(|view| view.read(cx).upload_progress)
+                        .unwrap_or(100.);
+                    v_flex()
+                        .mt_2()
+                        .gap_1()
+                        .child(Progress::new().value(progress))
+                        .child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(format!("{:.0}%", progress)),
+                        )
+                        .into_any_element()
+                })
+                .action(move |_, _, cx| {
+                    let view = cancel_view.clone();
+                    Button::new("cancel")
+                        .label("Cancel")
+                        .debug_selector(|| "cancel-upload-notification".into())
+                        .on_click(cx.listener(move |note, _, window, cx| {
+                            // Removing by id would read this notification while
+                            // it is being updated, so it dismisses itself
+                            _ = view.update(cx, |view, cx| view.stop_upload(cx));
+                            note.dismiss(window, cx);
+                        }))
+                }),
+            cx,
+        );
+
+        self._upload_task = Some(cx.spawn_in(window, async move |this, cx| {
+            for step in 1..=20 {
+                cx.background_executor()
+                    .timer(Duration::from_millis(150))
+                    .await;
+                let updated = this.update(cx, |this, cx| {
+                    this.upload_progress = Some(step as f32 * 5.);
+                    cx.notify();
+                });
+                if updated.is_err() {
+                    return;
+                }
+            }
+            _ = this.update_in(cx, |this, window, cx| {
+                this.upload_progress = None;
+                this._upload_task = None;
+                window.push_notification(
+                    Notification::success("report.pdf was uploaded.")
+                        .id::<UploadNotification>()
+                        .title("Upload complete"),
+                    cx,
+                );
+                cx.notify();
+            });
+        }));
+        cx.notify();
+    }
+
+    fn cancel_upload(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.stop_upload(cx);
+        window.remove_notification::<UploadNotification>(cx);
+    }
+
+    /// Drop the upload task, leaving its notification to the caller
+    fn stop_upload(&mut self, cx: &mut Context<Self>) {
+        self._upload_task = None;
+        self.upload_progress = None;
+        cx.notify();
+    }
+
+    fn burst(&self) -> AnyElement {
+        h_flex()
+            .gap_2()
+            .child(
+                Button::new("burst")
+                    .label(format!("Push {} Notifications", BURST_SIZE))
+                    .on_click(|_, window, cx| {
+                        for ix in 0..BURST_SIZE {
+                            let (type_, _, _) = TYPES[ix % TYPES.len()];
+                            window.push_notification(
+                                Notification::new()
+                                    .message(format!("Notification {} of {}", ix + 1, BURST_SIZE))
+                                    .with_type(type_),
+                                cx,
+                            );
+                        }
+                    }),
+            )
+            .child(
+                Button::new("clear-notifications")
+                    .label("Clear All")
+                    .ghost()
+                    .on_click(|_, window, cx| window.clear_notifications(cx)),
+            )
+            .into_any_element()
+    }
+}
@@ -0,0 +1,334 @@
+use gpui::*;
+use gpui_component::input::{InputEvent, InputState, MaskPattern, NumberInput};
+use gpui_component::input::{NumberInputEvent, StepAction};
+use gpui_component::*;
+
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+
+/// Pixels the mouse wheel scrolls for one step, so a trackpad doesn't step
+/// on every small scroll event
+const WHEEL_STEP: f32 = 20.;
+
+/// How a number field steps, clamps and shows its value
+#[derive(Clone, Copy)]
+struct NumberFormat {
+    step: f64,
+    min: f64,
+    max: f64,
+    precision: usize,
+    /// Separator grouping the digits, with the fraction digits always shown
+    separator: Option<char>,
+}
+
+impl NumberFormat {
+    fn new(step: f64) -> Self {
+        Self {
+            step,
+            min: f64::MIN,
+            max: f64::MAX,
+            precision: 0,
+            separator: None,
+        }
+    }
+
+    fn range(mut self, min: f64, max: f64) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    fn separator(mut self, separator: char) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    fn mask_pattern(&self) -> MaskPattern {
+        match self.separator {
+            Some(separator) => MaskPattern::Number {
+                separator: Some(separator),
+                fraction: Some(self.precision),
+            },
+            None => MaskPattern::None,
+        }
+    }
+
+    fn round(&self, value: f64) -> f64 {
+        let scale = 10f64.powi(self.precision as i32);
+        ((value * scale).round() / scale).clamp(self.min, self.max)
+    }
+
+    fn format(&self, value: f64) -> String {
+        format!("{:.*}", self.precision, value)
+    }
+}
+
+/// A number input clamping its value to a range and rounding it to a
+/// precision, the value is kept as a number and the text follows it
+struct NumberField {
+    state: Entity<InputState>,
+    value: f64,
+    format: NumberFormat,
+    /// Scroll distance not yet turned into a step
+    wheel: f32,
+}
+
+impl NumberField {
+    fn new(value: f64, format: NumberFormat, window: &mut Window, cx: &mut App) -> Self {
+        let value = format.round(value);
+        // The initial text is the default value rather than set afterwards,
+        // which would move the cursor to its end and scroll the input
+        let mask = format.mask_pattern();
+        let text = mask.mask(&format.format(value));
+        let state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .mask_pattern(mask)
+                .default_value(text)
+        });
+
+        Self {
+            state,
+            value,
+            format,
+            wheel: 0.,
+        }
+    }
+
+    fn set(&mut self, value: f64, window: &mut Window, cx: &mut App) {
+        self.value = self.format.round(value);
+        let text = self.format.format(self.value);
+        self.state
+            .update(cx, |state, cx| state.set_value(text, window, cx));
+    }
+
+    fn step(&mut self, action: StepAction, window: &mut Window, cx: &mut App) {
+        let value = match action {
+            StepAction::Increment => self.value + self.format.step,
+            StepAction::Decrement => self.value - self.format.step,
+        };
+        self.set(value, window, cx);
+    }
+
+    /// Parse the edited text, it is clamped and formatted once the input
+    /// loses focus
+    fn parse(&mut self, cx: &App) {
+        let text = self.state.read(cx).unmask_value();
+        if let Ok(value) = text.trim().parse::<f64>() {
+            self.value = value;
+        }
+    }
+
+    fn on_wheel(&mut self, event: &ScrollWheelEvent, window: &mut Window, cx: &mut App) -> bool {
+        if !self.state.read(cx).focus_handle(cx).is_focused(window) {
+            return false;
+        }
+        self.wheel += f32::from(event.delta.pixel_delta(window.line_height()).y);
+        while self.wheel.abs() >= WHEEL_STEP {
+            let action = if self.wheel > 0. {
+                StepAction::Increment
+            } else {
+                StepAction::Decrement
+            };
+            self.wheel -= WHEEL_STEP.copysign(self.wheel);
+            self.step(action, window, cx);
+        }
+        true
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Field {
+    Basic,
+    Range,
+    Decimal,
+    Wheel,
+    Currency,
+}
+
+pub struct NumberInputComponentView {
+    fields: Vec<NumberField>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ComponentMeta for NumberInputComponentView {
+    const NAME: &'static str = "Number Input";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataEntry;
+    const DESCRIPTION: &'static str = "A NumberInput is an Input with buttons to decrement and increment its value, \nthe Up and Down keys step it too. The buttons only emit a step event, \nthe view owns the value and applies the step, range and precision.";
+    const LINK: &'static str =
+        "https://longbridge.github.io/gpui-component/docs/components/number-input";
+
+    fn create(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        // In the order of `Field`
+        let fields = vec![
+            NumberField::new(1., NumberFormat::new(1.), window, cx),
+            NumberField::new(50., NumberFormat::new(5.).range(0., 100.), window, cx),
+            NumberField::new(
+                1.25,
+                NumberFormat::new(0.05).range(0., 10.).precision(2),
+                window,
+                cx,
+            ),
+            NumberField::new(20., NumberFormat::new(1.).range(-50., 50.), window, cx),
+            NumberField::new(
+                1234.5,
+                NumberFormat::new(10.)
+                    .range(0., 1_000_000.)
+                    .precision(2)
+                    .separator(','),
+                window,
+                cx,
+            ),
+        ];
+
+        let _subscriptions = fields
+            .iter()
+            .enumerate()
+            .flat_map(|(ix, field)| {
+                [
+                    cx.subscribe_in(
+                        &field.state,
+                        window,
+                        move |this, _, event: &NumberInputEvent, window, cx| {
+                            let NumberInputEvent::Step(action) = event;
+                            this.fields[ix].step(*action, window, cx);
+                            cx.notify();
+                        },
+                    ),
+                    cx.subscribe_in(
+                        &field.state,
+                        window,
+                        move |this, _, event: &InputEvent, window, cx| match event {
+                            InputEvent::Change => {
+                                this.fields[ix].parse(cx);
+                                cx.notify();
+                            }
+                            InputEvent::Blur | InputEvent::PressEnter { .. } => {
+                                let value = this.fields[ix].value;
+                                this.fields[ix].set(value, window, cx);
+                                cx.notify();
+                            }
+                            InputEvent::Focus => {}
+                        },
+                    ),
+                ]
+            })
+            .collect();
+
+        Self {
+            fields,
+            _subscriptions,
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_number_input", "Basic Number Input")
+                .render(|this, _, cx| this.basic_number_input(cx)),
+            Self::example("step_and_range", "Step and Range")
+                .render(|this, _, cx| this.step_and_range(cx)),
+            Self::example("decimal_precision", "Decimal Precision")
+                .render(|this, _, cx| this.decimal_precision(cx)),
+            Self::example("keyboard_and_mouse_wheel", "Keyboard and Mouse Wheel")
+                .render(|this, _, cx| this.keyboard_and_mouse_wheel(cx)),
+            Self::example("currency", "Currency").render(|this, _, cx| this.currency(cx)),
+        ]
+    }
+}
+
+impl NumberInputComponentView {
+    // Example code for the NumberInput component
+
+    fn basic_number_input(&self, cx: &mut Context<Self>) -> AnyElement {
+        self.field(
+            Field::Basic,
+            NumberInput::new(&self.field_state(Field::Basic)),
+            cx,
+        )
+    }
+
+    fn step_and_range(&self, cx: &mut Context<Self>) -> AnyElement {
+        self.field(
+            Field::Range,
+            NumberInput::new(&self.field_state(Field::Range)).suffix("%"),
+            cx,
+        )
+    }
+
+    fn decimal_precision(&self, cx: &mut Context<Self>) -> AnyElement {
+        self.field(
+            Field::Decimal,
+            NumberInput::new(&self.field_state(Field::Decimal)).suffix("kg"),
+            cx,
+        )
+    }
+
+    fn keyboard_and_mouse_wheel(&self, cx: &mut Context<Self>) -> AnyElement {
+        v_flex()
+            .gap_2()
+            .child(self.field(
+                Field::Wheel,
+                NumberInput::new(&self.field_state(Field::Wheel)).suffix("°C"),
+                cx,
+            ))
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child("Focus the input, then press Up and Down or scroll the mouse wheel."),
+            )
+            .into_any_element()
+    }
+
+    fn currency(&self, cx: &mut Context<Self>) -> AnyElement {
+        self.field(
+            Field::Currency,
+            NumberInput::new(&self.field_state(Field::Currency)).prefix("$"),
+            cx,
+        )
+    }
+
+    fn field_state(&self, field: Field) -> Entity<InputState> {
+        self.fields[field as usize].state.clone()
+    }
+
+    /// The number input stepped by the mouse wheel while focused, with the
+    /// options and value of the field
+    fn field(&self, field: Field, input: NumberInput, cx: &mut Context<Self>) -> AnyElement {
+        let ix = field as usize;
+        let number = &self.fields[ix];
+        let format = number.format;
+        let range = if format.min == f64::MIN {
+            "no limits".to_string()
+        } else {
+            format!("{} to {}", format.min, format.max)
+        };
+
+        v_flex()
+            .gap_1()
+            .child(
+                div()
+                    .id(("number-field", ix))
+                    .on_scroll_wheel(cx.listener(move |this, event, window, cx| {
+                        if this.fields[ix].on_wheel(event, window, cx) {
+                            cx.stop_propagation();
+                            cx.notify();
+                        }
+                    }))
+                    .child(input),
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format!(
+                        "Value: {} · Step: {} · Range: {} · Precision: {}",
+                        number.value, format.step, range, format.precision
+                    )),
+            )
+            .into_any_element()
+    }
+}
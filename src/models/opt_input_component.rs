@@ -0,0 +1,261 @@
+use std::time::Duration;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonGroup, ButtonVariants};
+use gpui_component::checkbox::Checkbox;
+use gpui_component::input::{InputEvent, OtpInput, OtpState};
+use gpui_component::spinner::Spinner;
+use gpui_component::*;
+
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+
+/// Key context around the OTP inputs, the input itself ignores everything
+/// but digits and backspace
+const CONTEXT: &str = "OtpDemo";
+
+const LENGTHS: [usize; 3] = [4, 6, 8];
+
+/// The code accepted by the simulated verification, the first digits of it
+/// for the shorter lengths
+const VALID_CODE: &str = "12345678";
+
+actions!(otp_demo, [PasteCode]);
+
+/// Bind paste to fill the OTP inputs from the clipboard
+pub fn init(cx: &mut App) {
+    cx.bind_keys([KeyBinding::new("secondary-v", PasteCode, Some(CONTEXT))]);
+}
+
+#[derive(Clone, PartialEq)]
+enum Verification {
+    Pending,
+    Verifying,
+    Verified,
+    Rejected,
+}
+
+pub struct OptInputComponentView {
+    basic: Entity<OtpState>,
+    pin: Entity<OtpState>,
+    length: usize,
+    masked: bool,
+    code: Entity<OtpState>,
+    verification: Verification,
+    _verify_task: Option<Task<()>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ComponentMeta for OptInputComponentView {
+    const NAME: &'static str = "OTP Input";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataEntry;
+    const DESCRIPTION: &'static str = "An input for one time passwords and PIN codes, a fixed number of digits shown in groups of boxes. \nThe digits can be masked, and a change event is emitted once every digit is entered, \nfor example to verify the code.";
+    const LINK: &'static str =
+        "https://longbridge.github.io/gpui-component/docs/components/otp-input";
+
+    fn create(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let length = LENGTHS[1];
+        let basic = cx.new(|cx| OtpState::new(4, window, cx));
+        let pin = cx.new(|cx| {
+            OtpState::new(4, window, cx)
+                .masked(true)
+                .default_value("12")
+        });
+        let code = cx.new(|cx| OtpState::new(length, window, cx));
+        let _subscriptions = vec![cx.subscribe_in(&code, window, Self::on_code_event)];
+
+        Self {
+            basic,
+            pin,
+            length,
+            masked: false,
+            code,
+            verification: Verification::Pending,
+            _verify_task: None,
+            _subscriptions,
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_otp_input", "Basic OTP Input")
+                .render(|this, _, cx| this.basic_otp_input(cx)),
+            Self::example("masked_pin", "Masked PIN").render(|this, _, _| this.masked_pin()),
+            Self::example("verification", "Verification")
+                .render(|this, _, cx| this.verification(cx)),
+        ]
+    }
+}
+
+impl OptInputComponentView {
+    // Example code for the OtpInput component
+
+    fn basic_otp_input(&self, cx: &mut Context<Self>) -> AnyElement {
+        h_flex()
+            .gap_4()
+            .child(OtpInput::new(&self.basic))
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format!("Value: {:?}", self.basic.read(cx).value())),
+            )
+            .into_any_element()
+    }
+
+    fn masked_pin(&self) -> AnyElement {
+        OtpInput::new(&self.pin).groups(1).into_any_element()
+    }
+
+    fn verification(&self, cx: &mut Context<Self>) -> AnyElement {
+        let length = self.length;
+
+        v_flex()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_4()
+                    .child(
+                        ButtonGroup::new("otp-length")
+                            .outline()
+                            .small()
+                            .children(LENGTHS.into_iter().map(|len| {
+                                Button::new(len)
+                                    .label(format!("{} digits", len))
+                                    .selected(len == length)
+                            }))
+                            .on_click(cx.listener(|this, selected: &Vec<usize>, window, cx| {
+                                if let Some(&ix) = selected.first() {
+                                    this.set_length(LENGTHS[ix], window, cx);
+                                }
+                            })),
+                    )
+                    .child(
+                        Checkbox::new("otp-masked")
+                            .label("Mask digits")
+                            .checked(self.masked)
+                            .on_click(cx.listener(|this, checked, window, cx| {
+                                this.masked = *checked;
+                                this.code
+                                    .update(cx, |code, cx| code.set_masked(*checked, window, cx));
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .id("otp-code")
+                    .gap_3()
+                    .key_context(CONTEXT)
+                    .on_action(cx.listener(|this, _: &PasteCode, window, cx| {
+                        this.paste_code(window, cx);
+                    }))
+                    .child(OtpInput::new(&self.code).groups(2))
+                    .child(
+                        Button::new("otp-paste")
+                            .icon(IconName::Copy)
+                            .ghost()
+                            .small()
+                            .tooltip("Paste code")
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.paste_code(window, cx);
+                            })),
+                    ),
+            )
+            .child(h_flex().gap_2().text_sm().map(|this| {
+                match self.verification {
+                    Verification::Pending => {
+                        this.text_color(cx.theme().muted_foreground).child(format!(
+                            "Enter or paste the code, {} is valid.",
+                            &VALID_CODE[..length]
+                        ))
+                    }
+                    Verification::Verifying => this
+                        .text_color(cx.theme().muted_foreground)
+                        .child(Spinner::new().small())
+                        .child("Verifying…"),
+                    Verification::Verified => this
+                        .text_color(cx.theme().success)
+                        .child(Icon::new(IconName::CircleCheck).small())
+                        .child("Code verified."),
+                    Verification::Rejected => this
+                        .text_color(cx.theme().danger)
+                        .child(Icon::new(IconName::CircleX).small())
+                        .child("Invalid code, try again."),
+                }
+            }))
+            .into_any_element()
+    }
+
+    /// Replace the code input by one with the given number of digits
+    fn set_length(&mut self, length: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let masked = self.masked;
+        self.length = length;
+        self.code = cx.new(|cx| OtpState::new(length, window, cx).masked(masked));
+        self._subscriptions = vec![cx.subscribe_in(&self.code, window, Self::on_code_event)];
+        self.verification = Verification::Pending;
+        self._verify_task = None;
+        cx.notify();
+    }
+
+    fn on_code_event(
+        &mut self,
+        state: &Entity<OtpState>,
+        event: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        // Only emitted once every digit is entered
+        if let InputEvent::Change = event {
+            let code = state.read(cx).value().clone();
+            self.verify(code, window, cx);
+        }
+    }
+
+    /// Fill the code with the digits on the clipboard, e.g. copied from an
+    /// SMS as "Your code is 123-456"
+    fn paste_code(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(text) = cx.read_from_clipboard().and_then(|item| item.text()) else {
+            return;
+        };
+        let code: String = text
+            .chars()
+            .filter(char::is_ascii_digit)
+            .take(self.length)
+            .collect();
+        if code.is_empty() {
+            return;
+        }
+
+        self.code.update(cx, |state, cx| {
+            state.set_value(code.clone(), window, cx);
+            state.focus(window, cx);
+        });
+        if code.len() == self.length {
+            self.verify(code.into(), window, cx);
+        } else {
+            self.verification = Verification::Pending;
+            cx.notify();
+        }
+    }
+
+    /// Verify the code after a simulated round trip to a server
+    fn verify(&mut self, code: SharedString, window: &mut Window, cx: &mut Context<Self>) {
+        self.verification = Verification::Verifying;
+        let valid = code.as_ref() == &VALID_CODE[..self.length];
+        self._verify_task = Some(cx.spawn_in(window, async move |this, cx| {
+            cx.background_executor()
+                .timer(Duration::from_millis(800))
+                .await;
+            _ = this.update(cx, |this, cx| {
+                this.verification = if valid {
+                    Verification::Verified
+                } else {
+                    Verification::Rejected
+                };
+                cx.notify();
+            });
+        }));
+        cx.notify();
+    }
+}
@@ -4,53 +4,54 @@ use gpui_component::popover::Popover;
 use gpui_component::*;
 use gpui_component::{button::Button, divider::Divider};
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
 
 pub struct PopoverComponentView {
     open: bool,
 }
 
-impl PopoverComponentView {
-    pub fn new() -> Self {
-        Self { open: false }
-    }
-}
-
 impl ComponentMeta for PopoverComponentView {
+    const NAME: &'static str = "Popover";
+    const CATEGORY: ComponentCategory = ComponentCategory::Overlay;
     const DESCRIPTION: &'static str = "Popover component for displaying floating content that appears when interacting with a trigger element. \nSupports multiple positioning options, custom content, different trigger methods, \nand automatic dismissal behaviors. Perfect for tooltips, menus, forms, and other contextual information.";
     const LINK: &'static str =
         "https://longbridge.github.io/gpui-component/docs/components/popover";
-}
 
-impl Render for PopoverComponentView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Basic Popover"))
-            .child(self.basic_popover())
-            .child(subtitle("Popover with Custom Positioning"))
-            .child(self.popover_with_custom_positioning())
-            .child(subtitle("View in Popover"))
-            .child(self.view_in_popover(_cx))
-            .child(subtitle("Add content by content method"))
-            .child(self.add_content_by_content_method())
-            .child(subtitle("Right-Click Popover"))
-            .child(self.right_click_popover())
-            .child(subtitle("Dismiss Popover manually"))
-            .child(self.dismiss_popover_manually())
-            .child(subtitle("Styling Popover"))
-            .child(self.styling_popover(_cx))
-            .child(subtitle("Control Open State"))
-            .child(self.control_open_state(_cx))
-            .child(subtitle("Default Open"))
-            .child(self.default_open())
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self { open: false }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_popover", "Basic Popover")
+                .render(|this, _, _| this.basic_popover()),
+            Self::example(
+                "popover_with_custom_positioning",
+                "Popover with Custom Positioning",
+            )
+            .render(|this, _, _| this.popover_with_custom_positioning()),
+            Self::example("view_in_popover", "View in Popover")
+                .render(|this, _, cx| this.view_in_popover(cx)),
+            Self::example(
+                "add_content_by_content_method",
+                "Add content by content method",
+            )
+            .render(|this, _, _| this.add_content_by_content_method()),
+            Self::example("right_click_popover", "Right-Click Popover")
+                .render(|this, _, _| this.right_click_popover()),
+            Self::example("dismiss_popover_manually", "Dismiss Popover manually")
+                .render(|this, _, _| this.dismiss_popover_manually()),
+            Self::example("styling_popover", "Styling Popover")
+                .render(|this, _, cx| this.styling_popover(cx)),
+            Self::example("control_open_state", "Control Open State")
+                .render(|this, _, cx| this.control_open_state(cx)),
+            Self::example("default_open", "Default Open").render(|this, _, _| this.default_open()),
+        ]
     }
 }
 
 impl PopoverComponentView {
-    /// Example code for the Popover component
+    // Example code for the Popover component
 
     fn basic_popover(&self) -> AnyElement {
         Popover::new("basic-popover")
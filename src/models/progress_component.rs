@@ -0,0 +1,112 @@
+use gpui::*;
+use gpui_component::progress::Progress;
+use gpui_component::*;
+
+use crate::models::simulated_job::{JobStatus, STAGES, SimulatedJob, job_controls};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+
+pub struct ProgressComponentView {
+    job: Entity<SimulatedJob>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ComponentMeta for ProgressComponentView {
+    const NAME: &'static str = "Progress";
+    const CATEGORY: ComponentCategory = ComponentCategory::Feedback;
+    const DESCRIPTION: &'static str = "A Progress bar shows how much of a task is done, from 0 to 100 percent. \nUse it when the amount of work is known, and a Spinner when it is not.";
+    const LINK: &'static str =
+        "https://longbridge.github.io/gpui-component/docs/components/progress";
+
+    fn create(_window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let job = SimulatedJob::global(cx);
+        let _subscriptions = vec![cx.observe(&job, |_, _, cx| cx.notify())];
+
+        Self {
+            job,
+            _subscriptions,
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_progress", "Basic Progress")
+                .render(|this, _, cx| this.basic_progress(cx)),
+            Self::example("custom_style", "Custom Color and Height")
+                .render(|this, _, cx| this.custom_style(cx)),
+            Self::example("background_job", "Background Job")
+                .render(|this, _, cx| this.background_job(cx)),
+        ]
+    }
+}
+
+impl ProgressComponentView {
+    // Example code for the Progress component
+
+    fn basic_progress(&self, cx: &mut Context<Self>) -> AnyElement {
+        v_flex()
+            .gap_3()
+            .children([0., 25., 60., 100.].map(|value| {
+                h_flex()
+                    .gap_3()
+                    .child(Progress::new().value(value))
+                    .child(percentage(value, cx))
+            }))
+            .into_any_element()
+    }
+
+    fn custom_style(&self, cx: &mut Context<Self>) -> AnyElement {
+        v_flex()
+            .gap_3()
+            .child(Progress::new().value(40.).bg(cx.theme().success))
+            .child(Progress::new().value(65.).bg(cx.theme().warning).h_1())
+            .child(
+                Progress::new()
+                    .value(85.)
+                    .bg(cx.theme().danger)
+                    .h_4()
+                    .rounded_sm(),
+            )
+            .into_any_element()
+    }
+
+    fn background_job(&self, cx: &mut Context<Self>) -> AnyElement {
+        let job = self.job.read(cx);
+        let color = match job.status() {
+            JobStatus::Failed => cx.theme().danger,
+            JobStatus::Completed => cx.theme().success,
+            JobStatus::Paused | JobStatus::Cancelled => cx.theme().muted_foreground,
+            JobStatus::Idle | JobStatus::Running => cx.theme().progress_bar,
+        };
+        let stage = STAGES.get(job.stage()).copied().unwrap_or("Done");
+
+        v_flex()
+            .gap_3()
+            .child(job_controls(&self.job, cx))
+            .child(
+                h_flex()
+                    .gap_3()
+                    .child(div().w_20().text_sm().child("Overall"))
+                    .child(Progress::new().value(job.progress()).bg(color))
+                    .child(percentage(job.progress(), cx)),
+            )
+            .child(
+                h_flex()
+                    .gap_3()
+                    .child(div().w_20().text_sm().child(stage))
+                    .child(Progress::new().value(job.stage_progress()).bg(color))
+                    .child(percentage(job.stage_progress(), cx)),
+            )
+            .child(div().text_sm().text_color(color).child(job.message()))
+            .into_any_element()
+    }
+}
+
+fn percentage(value: f32, cx: &App) -> Div {
+    div()
+        .w_10()
+        .flex_none()
+        .text_right()
+        .text_sm()
+        .text_color(cx.theme().muted_foreground)
+        .child(format!("{:.0}%", value))
+}
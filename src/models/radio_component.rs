@@ -0,0 +1,136 @@
+use gpui::*;
+use gpui_component::radio::{Radio, RadioGroup};
+use gpui_component::*;
+
+use crate::models::{ComponentCategory, ComponentMeta, Example, value_label};
+
+const PLANS: [&str; 3] = ["Free", "Pro", "Team"];
+
+const DENSITIES: [(&str, &str); 3] = [
+    ("Comfortable", "More space between rows, easier to scan."),
+    ("Default", "The spacing used across the gallery."),
+    ("Compact", "Fit more rows on the screen."),
+];
+
+/// Shipping methods and whether they can be picked for this order
+const SHIPPING: [(&str, bool); 4] = [
+    ("Standard, 5 to 7 days", true),
+    ("Express, 2 days", true),
+    ("Overnight, not available for this address", false),
+    ("Store pickup, no store nearby", false),
+];
+
+pub struct RadioComponentView {
+    plan: usize,
+    density: usize,
+    shipping: usize,
+}
+
+impl ComponentMeta for RadioComponentView {
+    const NAME: &'static str = "Radio";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataEntry;
+    const DESCRIPTION: &'static str = "A Radio picks exactly one option out of a set. \nA RadioGroup lays its radios out horizontally or vertically and reports the clicked index.";
+    const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/radio";
+
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self {
+            plan: 1,
+            density: 1,
+            shipping: 0,
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("horizontal_group", "Horizontal Group")
+                .render(|this, _, cx| this.horizontal_group(cx)),
+            Self::example("vertical_group", "Vertical Group with Descriptions")
+                .render(|this, _, cx| this.vertical_group(cx)),
+            Self::example("disabled_options", "Disabled Options")
+                .render(|this, _, cx| this.disabled_options(cx)),
+            Self::example("disabled_group", "Disabled Group")
+                .render(|this, _, _| this.disabled_group()),
+        ]
+    }
+}
+
+impl RadioComponentView {
+    // Example code for the Radio component
+
+    fn horizontal_group(&self, cx: &mut Context<Self>) -> AnyElement {
+        v_flex()
+            .gap_3()
+            .child(
+                RadioGroup::horizontal("plans")
+                    .children(PLANS)
+                    .selected_index(Some(self.plan))
+                    .on_click(cx.listener(|this, ix: &usize, _, cx| {
+                        this.plan = *ix;
+                        cx.notify();
+                    })),
+            )
+            .child(value_label(format!("Plan: {}", PLANS[self.plan]), cx))
+            .into_any_element()
+    }
+
+    fn vertical_group(&self, cx: &mut Context<Self>) -> AnyElement {
+        v_flex()
+            .gap_3()
+            .child(
+                RadioGroup::vertical("densities")
+                    .children(DENSITIES.into_iter().map(|(label, description)| {
+                        Radio::new(label).label(label).child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(description),
+                        )
+                    }))
+                    .selected_index(Some(self.density))
+                    .on_click(cx.listener(|this, ix: &usize, _, cx| {
+                        this.density = *ix;
+                        cx.notify();
+                    })),
+            )
+            .child(value_label(
+                format!("Density: {}", DENSITIES[self.density].0),
+                cx,
+            ))
+            .into_any_element()
+    }
+
+    fn disabled_options(&self, cx: &mut Context<Self>) -> AnyElement {
+        // A RadioGroup disables all of its radios or none, so options
+        // disabled one by one are plain radios sharing the view state
+        v_flex()
+            .gap_3()
+            .children(
+                SHIPPING
+                    .into_iter()
+                    .enumerate()
+                    .map(|(ix, (label, available))| {
+                        Radio::new(("shipping", ix))
+                            .label(label)
+                            .checked(self.shipping == ix)
+                            .disabled(!available)
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.shipping = ix;
+                                cx.notify();
+                            }))
+                    }),
+            )
+            .child(value_label(
+                format!("Shipping: {}", SHIPPING[self.shipping].0),
+                cx,
+            ))
+            .into_any_element()
+    }
+
+    fn disabled_group(&self) -> AnyElement {
+        RadioGroup::horizontal("disabled-plans")
+            .children(PLANS)
+            .selected_index(Some(0))
+            .disabled(true)
+            .into_any_element()
+    }
+}
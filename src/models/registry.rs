@@ -0,0 +1,166 @@
+use gpui::{AnyElement, AnyEntity, App, AppContext, Context, Window};
+use gpui_component::IconName;
+use strum_macros::{Display, EnumIter};
+
+use super::{Components, Example, ExampleInfo};
+
+/// Trait for component metadata - each component view should implement this
+///
+/// A view implementing this trait only needs to be listed once in
+/// `register_components!` to show up in the sidebar and the showcase.
+pub trait ComponentMeta: Sized + 'static {
+    /// Display name shown in the sidebar and as the showcase title
+    const NAME: &'static str;
+    /// Sidebar group the component belongs to
+    const CATEGORY: ComponentCategory;
+    const DESCRIPTION: &'static str;
+    const LINK: &'static str;
+
+    /// Create the view state for this component
+    fn create(window: &mut Window, cx: &mut Context<Self>) -> Self;
+
+    /// The examples of this component, in the order they are shown
+    fn examples() -> Vec<Example<Self>>;
+
+    /// Start a new example for this component
+    fn example(id: &'static str, title: &'static str) -> Example<Self> {
+        Example::new(id, title)
+    }
+}
+
+/// Sidebar groups for the registered components
+#[derive(Debug, Clone, Copy, Display, EnumIter, PartialEq, Eq)]
+pub enum ComponentCategory {
+    #[strum(to_string = "General")]
+    General,
+    #[strum(to_string = "Layout")]
+    Layout,
+    #[strum(to_string = "Data Entry")]
+    DataEntry,
+    #[strum(to_string = "Data Display")]
+    DataDisplay,
+    #[strum(to_string = "Feedback")]
+    Feedback,
+    #[strum(to_string = "Overlay")]
+    Overlay,
+}
+
+impl ComponentCategory {
+    pub fn icon(&self) -> IconName {
+        match self {
+            ComponentCategory::General => IconName::LayoutDashboard,
+            ComponentCategory::Layout => IconName::PanelLeft,
+            ComponentCategory::DataEntry => IconName::SquareTerminal,
+            ComponentCategory::DataDisplay => IconName::ChartPie,
+            ComponentCategory::Feedback => IconName::Bell,
+            ComponentCategory::Overlay => IconName::GalleryVerticalEnd,
+        }
+    }
+}
+
+/// A registered showcase page, built from the view's `ComponentMeta` impl
+pub struct ComponentEntry {
+    pub component: Components,
+    pub name: &'static str,
+    pub category: ComponentCategory,
+    pub description: &'static str,
+    pub link: &'static str,
+    build: fn(&mut Window, &mut App) -> AnyEntity,
+    examples: fn() -> Vec<ExampleInfo>,
+    render_examples: fn(&AnyEntity, &mut Window, &mut App) -> Vec<AnyElement>,
+}
+
+impl ComponentEntry {
+    pub const fn new<V: ComponentMeta>(component: Components) -> Self {
+        Self {
+            component,
+            name: V::NAME,
+            category: V::CATEGORY,
+            description: V::DESCRIPTION,
+            link: V::LINK,
+            build: build_view::<V>,
+            examples: example_infos::<V>,
+            render_examples: render_examples::<V>,
+        }
+    }
+
+    /// Create a new View for this component
+    pub fn build(&self, window: &mut Window, cx: &mut App) -> AnyEntity {
+        (self.build)(window, cx)
+    }
+
+    /// The metadata of every example of this component
+    pub fn examples(&self) -> Vec<ExampleInfo> {
+        (self.examples)()
+    }
+
+    /// Render every example of a view created by [`ComponentEntry::build`],
+    /// in the same order as [`ComponentEntry::examples`]
+    pub fn render_examples(
+        &self,
+        view: &AnyEntity,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Vec<AnyElement> {
+        (self.render_examples)(view, window, cx)
+    }
+}
+
+fn build_view<V: ComponentMeta>(window: &mut Window, cx: &mut App) -> AnyEntity {
+    cx.new(|cx| V::create(window, cx)).into_any()
+}
+
+fn example_infos<V: ComponentMeta>() -> Vec<ExampleInfo> {
+    V::examples().iter().map(Example::info).collect()
+}
+
+fn render_examples<V: ComponentMeta>(
+    view: &AnyEntity,
+    window: &mut Window,
+    cx: &mut App,
+) -> Vec<AnyElement> {
+    let Ok(view) = view.clone().downcast::<V>() else {
+        panic!("view is not a {}", V::NAME);
+    };
+
+    view.update(cx, |view, cx| {
+        V::examples()
+            .iter()
+            .map(|example| example.render_element(view, window, cx))
+            .collect()
+    })
+}
+
+/// Declare the showcase page modules, the `Components` enum and the registry
+/// of showcase pages.
+///
+/// Each line maps an enum variant to the view implementing `ComponentMeta` in
+/// its module, which is declared and re-exported here. The order of the lines
+/// is the order of the pages in the sidebar.
+macro_rules! register_components {
+    ($($variant:ident => $module:ident :: $view:ident),* $(,)?) => {
+        $(pub mod $module;)*
+        $(pub use $module::*;)*
+
+        #[derive(
+            Debug,
+            Clone,
+            Copy,
+            PartialEq,
+            strum_macros::Display,
+            strum_macros::EnumString,
+            strum_macros::EnumIter,
+        )]
+        #[strum(ascii_case_insensitive)]
+        pub enum Components {
+            $($variant,)*
+        }
+
+        /// All registered showcase pages, in the same order as `Components`
+        pub static REGISTRY: &[$crate::models::ComponentEntry] = &[
+            $($crate::models::ComponentEntry::new::<$module::$view>(Components::$variant),)*
+        ];
+    };
+}
+
+pub(crate) use register_components;
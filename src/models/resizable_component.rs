@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::Button;
+use gpui_component::resizable::{ResizableState, h_resizable, resizable_panel, v_resizable};
+use gpui_component::*;
+
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+
+/// The panel sizes of the examples, kept for the whole run so a split stays
+/// where it was dragged after switching to another page and back
+#[derive(Default)]
+struct SplitStates(HashMap<&'static str, Entity<ResizableState>>);
+
+impl Global for SplitStates {}
+
+/// The state of the panel group with the key, shared by every instance of
+/// the page
+fn split_state(key: &'static str, cx: &mut App) -> Entity<ResizableState> {
+    if let Some(state) = cx.default_global::<SplitStates>().0.get(key) {
+        return state.clone();
+    }
+    let state = cx.new(|_| ResizableState::default());
+    cx.global_mut::<SplitStates>().0.insert(key, state.clone());
+    state
+}
+
+pub struct ResizableComponentView {
+    horizontal: Entity<ResizableState>,
+    vertical: Entity<ResizableState>,
+    workbench: Entity<ResizableState>,
+    workbench_editor: Entity<ResizableState>,
+    limits: Entity<ResizableState>,
+    collapsible: Entity<ResizableState>,
+    sidebar_visible: bool,
+}
+
+impl ComponentMeta for ResizableComponentView {
+    const NAME: &'static str = "Resizable";
+    const CATEGORY: ComponentCategory = ComponentCategory::Layout;
+    const DESCRIPTION: &'static str = "Resizable panel groups split the space horizontally or vertically, \nthe handles between the panels are dragged to resize them. Groups can be nested, \nand the sizes live in a ResizableState which can outlive the view.";
+    const LINK: &'static str =
+        "https://longbridge.github.io/gpui-component/docs/components/resizable";
+
+    fn create(_window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            horizontal: split_state("horizontal", cx),
+            vertical: split_state("vertical", cx),
+            workbench: split_state("workbench", cx),
+            workbench_editor: split_state("workbench-editor", cx),
+            limits: split_state("limits", cx),
+            collapsible: split_state("collapsible", cx),
+            sidebar_visible: true,
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("horizontal_panels", "Horizontal Panels")
+                .render(|this, _, cx| this.horizontal_panels(cx)),
+            Self::example("vertical_panels", "Vertical Panels")
+                .render(|this, _, cx| this.vertical_panels(cx)),
+            Self::example("nested_panels", "Nested Panels")
+                .render(|this, _, cx| this.nested_panels(cx)),
+            Self::example("size_limits", "Minimum and Maximum Sizes")
+                .render(|this, _, cx| this.size_limits(cx)),
+            Self::example("collapsible_panel", "Collapsible Panel")
+                .render(|this, _, cx| this.collapsible_panel(cx)),
+        ]
+    }
+}
+
+impl ResizableComponentView {
+    // Example code for the Resizable component
+
+    fn horizontal_panels(&self, cx: &mut Context<Self>) -> AnyElement {
+        // Sizes are empty until the group is laid out for the first time
+        let sizes = self.horizontal.read(cx).sizes();
+        let total: f32 = sizes.iter().map(|size| f32::from(*size)).sum();
+        let ratios = sizes
+            .iter()
+            .map(|size| format!("{:.0}%", f32::from(*size) / total * 100.))
+            .collect::<Vec<_>>();
+
+        v_flex()
+            .gap_2()
+            .child(
+                frame(cx).child(
+                    h_resizable("horizontal")
+                        .with_state(&self.horizontal)
+                        .child(resizable_panel().size(px(200.)).child(pane("Left", cx)))
+                        .child(resizable_panel().child(pane("Right", cx))),
+                ),
+            )
+            .when(total > 0., |this| {
+                this.child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(format!(
+                            "Split: {}, kept when you switch pages and come back.",
+                            ratios.join(" · ")
+                        )),
+                )
+            })
+            .into_any_element()
+    }
+
+    fn vertical_panels(&self, cx: &mut Context<Self>) -> AnyElement {
+        frame(cx)
+            .child(
+                v_resizable("vertical")
+                    .with_state(&self.vertical)
+                    .child(resizable_panel().child(pane("Top", cx)))
+                    .child(resizable_panel().size(px(80.)).child(pane("Bottom", cx))),
+            )
+            .into_any_element()
+    }
+
+    fn nested_panels(&self, cx: &mut Context<Self>) -> AnyElement {
+        frame(cx)
+            .h(px(320.))
+            .child(
+                h_resizable("workbench")
+                    .with_state(&self.workbench)
+                    .child(resizable_panel().size(px(180.)).child(pane("Explorer", cx)))
+                    .child(
+                        v_resizable("workbench-editor")
+                            .with_state(&self.workbench_editor)
+                            .child(resizable_panel().child(pane("Editor", cx)))
+                            .child(resizable_panel().size(px(100.)).child(pane("Terminal", cx))),
+                    )
+                    .child(resizable_panel().size(px(160.)).child(pane("Outline", cx))),
+            )
+            .into_any_element()
+    }
+
+    fn size_limits(&self, cx: &mut Context<Self>) -> AnyElement {
+        frame(cx)
+            .child(
+                h_resizable("limits")
+                    .with_state(&self.limits)
+                    .child(
+                        resizable_panel()
+                            .size(px(200.))
+                            .size_range(px(150.)..px(300.))
+                            .child(pane("150 – 300 px", cx)),
+                    )
+                    .child(
+                        resizable_panel()
+                            .size_range(px(200.)..Pixels::MAX)
+                            .child(pane("At least 200 px", cx)),
+                    )
+                    .child(
+                        resizable_panel()
+                            .size(px(120.))
+                            .size_range(px(120.)..px(120.))
+                            .child(pane("Fixed 120 px", cx)),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    fn collapsible_panel(&self, cx: &mut Context<Self>) -> AnyElement {
+        let visible = self.sidebar_visible;
+
+        v_flex()
+            .gap_2()
+            .child(
+                h_flex().child(
+                    Button::new("toggle-sidebar")
+                        .icon(if visible {
+                            IconName::PanelLeftClose
+                        } else {
+                            IconName::PanelLeftOpen
+                        })
+                        .label(if visible {
+                            "Collapse sidebar"
+                        } else {
+                            "Expand sidebar"
+                        })
+                        .small()
+                        .on_click(cx.listener(|this, _, _, cx| {
+                            this.sidebar_visible = !this.sidebar_visible;
+                            cx.notify();
+                        })),
+                ),
+            )
+            .child(
+                frame(cx).child(
+                    h_resizable("collapsible")
+                        .with_state(&self.collapsible)
+                        // A hidden panel takes no space, it gets its size back
+                        // when shown again
+                        .child(
+                            resizable_panel()
+                                .size(px(200.))
+                                .visible(visible)
+                                .child(pane("Sidebar", cx)),
+                        )
+                        .child(resizable_panel().child(pane("Content", cx))),
+                ),
+            )
+            .into_any_element()
+    }
+}
+
+fn frame(cx: &App) -> Div {
+    div()
+        .h(px(200.))
+        .border_1()
+        .border_color(cx.theme().border)
+        .rounded(cx.theme().radius)
+        .overflow_hidden()
+}
+
+fn pane(label: &'static str, cx: &App) -> Div {
+    div()
+        .size_full()
+        .flex()
+        .items_center()
+        .justify_center()
+        .bg(cx.theme().secondary)
+        .text_sm()
+        .text_color(cx.theme().muted_foreground)
+        .child(label)
+}
@@ -0,0 +1,119 @@
+use std::cmp::Reverse;
+
+use super::{Components, ExampleInfo, REGISTRY};
+
+/// Maximum number of results returned by [`search`]
+const MAX_RESULTS: usize = 50;
+
+/// A component page, or one example on it, matching a search query
+#[derive(Debug, Clone)]
+pub struct SearchMatch {
+    pub component: Components,
+    /// Set when the match is an example rather than the component page itself
+    pub example: Option<ExampleInfo>,
+    pub score: i64,
+}
+
+impl SearchMatch {
+    pub fn title(&self) -> String {
+        match &self.example {
+            Some(example) => format!("{} › {}", self.component.name(), example.title),
+            None => self.component.name().to_string(),
+        }
+    }
+}
+
+/// Fuzzy search the registered components, their descriptions and the titles
+/// of their examples, best matches first.
+///
+/// An empty query lists every component in sidebar order.
+pub fn search(query: &str) -> Vec<SearchMatch> {
+    let query = query.trim().to_lowercase();
+    let mut matches = Vec::new();
+
+    for entry in REGISTRY {
+        if query.is_empty() {
+            matches.push(SearchMatch {
+                component: entry.component,
+                example: None,
+                score: 0,
+            });
+            continue;
+        }
+
+        let name_score = fuzzy_score(&query, entry.name).map(|score| score * 2);
+        let description_score = description_score(&query, entry.description);
+        if let Some(score) = name_score.max(description_score) {
+            matches.push(SearchMatch {
+                component: entry.component,
+                example: None,
+                score,
+            });
+        }
+
+        for example in entry.examples() {
+            let title_score = fuzzy_score(&query, example.title);
+            let qualified_score = fuzzy_score(&query, &format!("{} {}", entry.name, example.title));
+            if let Some(score) = title_score.max(qualified_score) {
+                matches.push(SearchMatch {
+                    component: entry.component,
+                    example: Some(example),
+                    score,
+                });
+            }
+        }
+    }
+
+    // `sort_by_key` is stable, so equal scores keep the registry order
+    matches.sort_by_key(|m| Reverse(m.score));
+    matches.truncate(MAX_RESULTS);
+    matches
+}
+
+/// Score `text` against a lowercase `query` whose characters must all appear
+/// in order; consecutive characters and word starts score higher.
+///
+/// Whitespace in the query is ignored, so "date rng" matches "Date Range".
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    let text = text.to_lowercase().chars().collect::<Vec<_>>();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous_match: Option<usize> = None;
+
+    for query_char in query.chars().filter(|c| !c.is_whitespace()) {
+        let found = text[position..]
+            .iter()
+            .position(|c| *c == query_char)
+            .map(|offset| position + offset)?;
+
+        let word_start = found == 0 || !text[found - 1].is_alphanumeric();
+        score += match previous_match {
+            Some(previous) if previous + 1 == found => 8,
+            _ if word_start => 6,
+            _ => 1,
+        };
+        if let Some(previous) = previous_match {
+            // Penalize the gap between two matched characters
+            score -= (found - previous - 1).min(4) as i64;
+        }
+
+        previous_match = Some(found);
+        position = found + 1;
+    }
+
+    if text.starts_with(&query.chars().collect::<Vec<_>>()) {
+        score += 10;
+    }
+
+    Some(score)
+}
+
+/// Descriptions are long, so they only match when every word of the query
+/// appears literally, and score lower than a name match.
+fn description_score(query: &str, description: &str) -> Option<i64> {
+    let description = description.to_lowercase();
+    query
+        .split_whitespace()
+        .all(|word| description.contains(word))
+        .then_some(query.split_whitespace().count() as i64 * 4)
+}
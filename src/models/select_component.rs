@@ -0,0 +1,470 @@
+use std::time::Duration;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::select::{
+    SearchableVec, Select, SelectEvent, SelectGroup, SelectItem, SelectState,
+};
+use gpui_component::spinner::Spinner;
+use gpui_component::*;
+
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+
+/// Options of the searchable example
+const SEARCHABLE_OPTIONS: usize = 5_000;
+
+const ADJECTIVES: [&str; 10] = [
+    "Amber", "Brisk", "Cedar", "Dusky", "Ember", "Frost", "Golden", "Hollow", "Ivory", "Jade",
+];
+
+const NOUNS: [&str; 10] = [
+    "Bay", "Creek", "Dale", "Falls", "Grove", "Harbor", "Meadow", "Ridge", "Springs", "Valley",
+];
+
+/// Generated place names, e.g. "Amber Falls 0042"
+fn place_names(count: usize) -> Vec<SharedString> {
+    (0..count)
+        .map(|ix| {
+            format!(
+                "{} {} {:04}",
+                ADJECTIVES[ix % ADJECTIVES.len()],
+                NOUNS[(ix / ADJECTIVES.len()) % NOUNS.len()],
+                ix
+            )
+            .into()
+        })
+        .collect()
+}
+
+/// A programming language, rendered with an icon and a description
+#[derive(Clone)]
+struct Language {
+    name: SharedString,
+    description: &'static str,
+    icon: IconName,
+}
+
+impl Language {
+    fn all() -> Vec<Self> {
+        vec![
+            Language {
+                name: "Rust".into(),
+                description: "Fast, reliable, memory safe",
+                icon: IconName::SquareTerminal,
+            },
+            Language {
+                name: "TypeScript".into(),
+                description: "JavaScript with types",
+                icon: IconName::Globe,
+            },
+            Language {
+                name: "Python".into(),
+                description: "Batteries included",
+                icon: IconName::BookOpen,
+            },
+            Language {
+                name: "Go".into(),
+                description: "Simple concurrent services",
+                icon: IconName::Building2,
+            },
+        ]
+    }
+}
+
+impl SelectItem for Language {
+    type Value = SharedString;
+
+    fn title(&self) -> SharedString {
+        self.name.clone()
+    }
+
+    fn display_title(&self) -> Option<AnyElement> {
+        Some(
+            h_flex()
+                .gap_2()
+                .child(Icon::new(self.icon.clone()).small())
+                .child(self.name.clone())
+                .into_any_element(),
+        )
+    }
+
+    fn render(&self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        h_flex()
+            .gap_2()
+            .child(Icon::new(self.icon.clone()).small())
+            .child(
+                v_flex().child(self.name.clone()).child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(self.description),
+                ),
+            )
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.name
+    }
+
+    fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.name.to_lowercase().contains(&query)
+            || self.description.to_lowercase().contains(&query)
+    }
+}
+
+/// A subscription plan, some of them can't be picked
+#[derive(Clone)]
+struct Plan {
+    name: SharedString,
+    price: &'static str,
+    available: bool,
+}
+
+impl Plan {
+    fn all() -> Vec<Self> {
+        vec![
+            Plan {
+                name: "Free".into(),
+                price: "$0",
+                available: true,
+            },
+            Plan {
+                name: "Pro".into(),
+                price: "$12 / month",
+                available: true,
+            },
+            Plan {
+                name: "Team".into(),
+                price: "$40 / month",
+                available: false,
+            },
+            Plan {
+                name: "Enterprise".into(),
+                price: "Contact us",
+                available: false,
+            },
+        ]
+    }
+}
+
+impl SelectItem for Plan {
+    type Value = SharedString;
+
+    fn title(&self) -> SharedString {
+        self.name.clone()
+    }
+
+    fn render(&self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        h_flex()
+            .w_full()
+            .gap_4()
+            .justify_between()
+            .when(!self.available, |this| {
+                this.text_color(cx.theme().muted_foreground.opacity(0.6))
+            })
+            .child(self.name.clone())
+            .child(div().text_xs().child(if self.available {
+                self.price
+            } else {
+                "Unavailable"
+            }))
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.name
+    }
+}
+
+pub struct SelectComponentView {
+    basic: Entity<SelectState<Vec<&'static str>>>,
+    searchable: Entity<SelectState<SearchableVec<SharedString>>>,
+    grouped: Entity<SelectState<SearchableVec<SelectGroup<&'static str>>>>,
+    languages: Entity<SelectState<SearchableVec<Language>>>,
+    plans: Entity<SelectState<Vec<Plan>>>,
+    /// Last available plan picked, restored when an unavailable one is confirmed
+    plan: Option<SharedString>,
+    cleanable: Entity<SelectState<Vec<&'static str>>>,
+    disabled: Entity<SelectState<Vec<&'static str>>>,
+    remote: Entity<SelectState<SearchableVec<SharedString>>>,
+    loading: bool,
+    _load_task: Option<Task<()>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ComponentMeta for SelectComponentView {
+    const NAME: &'static str = "Select";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataEntry;
+    const DESCRIPTION: &'static str = "A dropdown to pick one value from a list of options. \nOptions can be searched, grouped under section headers and rendered with custom elements, \nand can come from any SelectDelegate, like a list loaded in the background.";
+    const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/select";
+
+    fn create(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let basic = cx.new(|cx| {
+            SelectState::new(
+                vec!["Apple", "Banana", "Cherry", "Durian"],
+                None,
+                window,
+                cx,
+            )
+        });
+        let searchable = cx.new(|cx| {
+            SelectState::new(
+                SearchableVec::new(place_names(SEARCHABLE_OPTIONS)),
+                None,
+                window,
+                cx,
+            )
+            .searchable(true)
+        });
+        let grouped = cx.new(|cx| {
+            SelectState::new(
+                SearchableVec::new(vec![
+                    SelectGroup::new("Fruits").items(["Apple", "Banana", "Cherry"]),
+                    SelectGroup::new("Vegetables").items(["Carrot", "Leek", "Potato"]),
+                    SelectGroup::new("Grains").items(["Barley", "Oats", "Rice"]),
+                ]),
+                None,
+                window,
+                cx,
+            )
+            .searchable(true)
+        });
+        let languages = cx.new(|cx| {
+            SelectState::new(
+                SearchableVec::new(Language::all()),
+                Some(IndexPath::new(0)),
+                window,
+                cx,
+            )
+            .searchable(true)
+        });
+        let plans = cx.new(|cx| SelectState::new(Plan::all(), Some(IndexPath::new(0)), window, cx));
+        let cleanable = cx.new(|cx| {
+            SelectState::new(
+                vec!["Small", "Medium", "Large"],
+                Some(IndexPath::new(1)),
+                window,
+                cx,
+            )
+        });
+        let disabled = cx.new(|cx| SelectState::new(vec!["Apple", "Banana"], None, window, cx));
+        let remote = cx.new(|cx| {
+            SelectState::new(SearchableVec::new(Vec::new()), None, window, cx).searchable(true)
+        });
+
+        let _subscriptions = vec![cx.subscribe_in(&plans, window, Self::on_plan_event)];
+
+        let mut this = Self {
+            basic,
+            searchable,
+            grouped,
+            languages,
+            plans,
+            plan: Some("Free".into()),
+            cleanable,
+            disabled,
+            remote,
+            loading: false,
+            _load_task: None,
+            _subscriptions,
+        };
+        this.load_remote_options(window, cx);
+        this
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_select", "Basic Select")
+                .render(|this, _, cx| this.basic_select(cx)),
+            Self::example("searchable_select", "Searchable Select")
+                .description("5,000 options, type in the menu to filter them.")
+                .render(|this, _, _| this.searchable_select()),
+            Self::example("grouped_options", "Grouped Options")
+                .render(|this, _, _| this.grouped_options()),
+            Self::example("custom_item_renderer", "Custom Item Renderer")
+                .description("Items with an icon and a description, searchable by both.")
+                .render(|this, _, _| this.custom_item_renderer()),
+            Self::example("disabled_options", "Disabled Options")
+                .render(|this, _, cx| this.disabled_options(cx)),
+            Self::example("cleanable_select", "Cleanable Select")
+                .render(|this, _, cx| this.cleanable_select(cx)),
+            Self::example("disabled_select", "Disabled Select")
+                .render(|this, _, _| this.disabled_select()),
+            Self::example("async_options", "Async Options")
+                .description("The options are built by a background task, the select is disabled until they arrive.")
+                .render(|this, _, cx| this.async_options(cx)),
+        ]
+    }
+}
+
+impl SelectComponentView {
+    /// Select has no disabled items, so picking an unavailable plan puts back
+    /// the previous one
+    fn on_plan_event(
+        &mut self,
+        state: &Entity<SelectState<Vec<Plan>>>,
+        event: &SelectEvent<Vec<Plan>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let SelectEvent::Confirm(value) = event;
+        let available = value.as_ref().is_none_or(|value| {
+            Plan::all()
+                .iter()
+                .any(|plan| &plan.name == value && plan.available)
+        });
+
+        if available {
+            self.plan = value.clone();
+        } else if let Some(plan) = self.plan.clone() {
+            state.update(cx, |state, cx| state.set_selected_value(&plan, window, cx));
+            window.push_notification(
+                format!(
+                    "{} is not available on this account.",
+                    value.clone().unwrap_or_default()
+                ),
+                cx,
+            );
+        }
+        cx.notify();
+    }
+
+    /// Build the options on the background executor, like a request to a server
+    fn load_remote_options(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.loading = true;
+        self.remote.update(cx, |state, cx| {
+            state.set_selected_index(None, window, cx);
+            state.set_items(SearchableVec::new(Vec::new()), window, cx);
+        });
+
+        // Replacing the task cancels a load still running
+        self._load_task = Some(cx.spawn_in(window, async move |this, cx| {
+            cx.background_executor()
+                .timer(Duration::from_millis(1500))
+                .await;
+            let options = cx
+                .background_spawn(async move {
+                    (1..=200)
+                        .map(|ix| SharedString::from(format!("Repository {:03}", ix)))
+                        .collect::<Vec<_>>()
+                })
+                .await;
+
+            this.update_in(cx, |this, window, cx| {
+                this.remote.update(cx, |state, cx| {
+                    state.set_items(SearchableVec::new(options), window, cx);
+                });
+                this.loading = false;
+                cx.notify();
+            })
+            .ok();
+        }));
+        cx.notify();
+    }
+
+    // Example code for the Select component
+
+    fn basic_select(&self, cx: &mut Context<Self>) -> AnyElement {
+        let selected = self.basic.read(cx).selected_value().copied();
+
+        v_flex()
+            .gap_2()
+            .child(Select::new(&self.basic).placeholder("Pick a fruit"))
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format!("Selected: {}", selected.unwrap_or("none"))),
+            )
+            .into_any_element()
+    }
+
+    fn searchable_select(&self) -> AnyElement {
+        Select::new(&self.searchable)
+            .placeholder("Pick a place")
+            .search_placeholder("Search 5,000 places…")
+            .into_any_element()
+    }
+
+    fn grouped_options(&self) -> AnyElement {
+        Select::new(&self.grouped)
+            .placeholder("Pick an ingredient")
+            .into_any_element()
+    }
+
+    fn custom_item_renderer(&self) -> AnyElement {
+        Select::new(&self.languages)
+            .menu_width(px(280.))
+            .into_any_element()
+    }
+
+    fn disabled_options(&self, cx: &mut Context<Self>) -> AnyElement {
+        v_flex()
+            .gap_2()
+            .child(Select::new(&self.plans).menu_width(px(240.)))
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format!(
+                        "Current plan: {}",
+                        self.plan.clone().unwrap_or_default()
+                    )),
+            )
+            .into_any_element()
+    }
+
+    fn cleanable_select(&self, cx: &mut Context<Self>) -> AnyElement {
+        let selected = self.cleanable.read(cx).selected_value().copied();
+
+        v_flex()
+            .gap_2()
+            .child(
+                Select::new(&self.cleanable)
+                    .placeholder("Pick a size")
+                    .cleanable(true),
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format!("Selected: {}", selected.unwrap_or("none"))),
+            )
+            .into_any_element()
+    }
+
+    fn disabled_select(&self) -> AnyElement {
+        Select::new(&self.disabled)
+            .placeholder("Not available")
+            .disabled(true)
+            .into_any_element()
+    }
+
+    fn async_options(&self, cx: &mut Context<Self>) -> AnyElement {
+        h_flex()
+            .gap_2()
+            .child(
+                Select::new(&self.remote)
+                    .placeholder(if self.loading {
+                        "Loading repositories…"
+                    } else {
+                        "Pick a repository"
+                    })
+                    .disabled(self.loading)
+                    .flex_1(),
+            )
+            .when(self.loading, |this| this.child(Spinner::new().small()))
+            .child(
+                Button::new("select-reload")
+                    .icon(IconName::Redo)
+                    .ghost()
+                    .small()
+                    .disabled(self.loading)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.load_remote_options(window, cx);
+                    })),
+            )
+            .into_any_element()
+    }
+}
@@ -0,0 +1,176 @@
+use gpui::*;
+use gpui_component::group_box::GroupBoxVariant;
+use gpui_component::setting::{
+    NumberFieldOptions, SettingField, SettingGroup, SettingItem, SettingPage, Settings,
+};
+use gpui_component::*;
+use strum::IntoEnumIterator;
+
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+use crate::preferences::{Preferences, SIDEBAR_WIDTH_RANGE, TAB_SIZE_RANGE, ThemePreference};
+
+pub struct SettingsComponentView;
+
+impl ComponentMeta for SettingsComponentView {
+    const NAME: &'static str = "Settings";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataEntry;
+    const DESCRIPTION: &'static str = "Settings lays out pages of setting groups with a searchable sidebar. \nEvery field reads and writes its value through a pair of functions, \nhere they edit the preferences of this gallery, which apply right away and are saved.";
+    const LINK: &'static str =
+        "https://longbridge.github.io/gpui-component/docs/components/settings";
+
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("gallery_settings", "Gallery Settings")
+                .render(|this, _, cx| this.gallery_settings(cx)),
+            Self::example("saved_preferences", "Saved Preferences")
+                .render(|this, _, cx| this.saved_preferences(cx)),
+        ]
+    }
+}
+
+impl SettingsComponentView {
+    // Example code for the Settings component
+
+    fn gallery_settings(&self, cx: &mut Context<Self>) -> AnyElement {
+        div()
+            .h(px(420.))
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(cx.theme().radius)
+            .overflow_hidden()
+            .child(
+                Settings::new("gallery-settings")
+                    .sidebar_width(px(180.))
+                    .with_group_variant(GroupBoxVariant::Outline)
+                    .pages([
+                        appearance_page().default_open(true),
+                        sidebar_page(),
+                        editor_page(),
+                        charts_page(),
+                    ]),
+            )
+            .into_any_element()
+    }
+
+    fn saved_preferences(&self, cx: &mut Context<Self>) -> AnyElement {
+        let json = serde_json::to_string_pretty(Preferences::global(cx)).unwrap_or_default();
+        let path = Preferences::path()
+            .map_or("No config directory on this platform".to_string(), |path| {
+                path.display().to_string()
+            });
+
+        v_flex()
+            .gap_2()
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(path),
+            )
+            .child(
+                div()
+                    .p_3()
+                    .rounded(cx.theme().radius)
+                    .bg(cx.theme().secondary)
+                    .font_family(cx.theme().mono_font_family.clone())
+                    .text_xs()
+                    .child(json),
+            )
+            .into_any_element()
+    }
+}
+
+/// Save the preferences and redraw every window, so the whole gallery
+/// follows the change right away
+fn update_preferences(cx: &mut App, f: impl FnOnce(&mut Preferences)) {
+    Preferences::update(cx, f);
+    cx.refresh_windows();
+}
+
+fn appearance_page() -> SettingPage {
+    let modes = ThemePreference::iter()
+        .map(|mode| (mode.to_string().into(), mode.to_string().into()))
+        .collect();
+    let theme = SettingField::dropdown(
+        modes,
+        |cx| Preferences::global(cx).theme_mode().to_string().into(),
+        |mode: SharedString, cx| {
+            let Ok(mode) = mode.parse::<ThemePreference>() else {
+                return;
+            };
+            update_preferences(cx, |preferences| preferences.set_theme(mode));
+            mode.apply(None, cx);
+        },
+    )
+    .default_value(ThemePreference::default().to_string());
+
+    SettingPage::new("Appearance").group(
+        SettingGroup::new().title("Theme").item(
+            SettingItem::new("Theme Mode", theme)
+                .description("System follows the appearance of the operating system."),
+        ),
+    )
+}
+
+fn sidebar_page() -> SettingPage {
+    let width = SettingField::number_input(
+        NumberFieldOptions {
+            min: *SIDEBAR_WIDTH_RANGE.start() as f64,
+            max: *SIDEBAR_WIDTH_RANGE.end() as f64,
+            step: 10.,
+        },
+        |cx| Preferences::global(cx).sidebar_width as f64,
+        |width, cx| update_preferences(cx, |preferences| preferences.sidebar_width = width as f32),
+    )
+    .default_value(Preferences::default().sidebar_width as f64);
+
+    SettingPage::new("Sidebar").group(
+        SettingGroup::new().title("Layout").item(
+            SettingItem::new("Sidebar Width", width)
+                .description("Width of the expanded sidebar, in pixels."),
+        ),
+    )
+}
+
+fn editor_page() -> SettingPage {
+    let tab_size = SettingField::number_input(
+        NumberFieldOptions {
+            min: *TAB_SIZE_RANGE.start() as f64,
+            max: *TAB_SIZE_RANGE.end() as f64,
+            step: 1.,
+        },
+        |cx| Preferences::global(cx).editor_tab_size as f64,
+        |size, cx| {
+            update_preferences(cx, |preferences| {
+                preferences.editor_tab_size = size as usize
+            })
+        },
+    )
+    .default_value(Preferences::default().editor_tab_size as f64);
+
+    SettingPage::new("Editor").group(
+        SettingGroup::new().title("Indentation").item(
+            SettingItem::new("Tab Size", tab_size)
+                .description("Spaces inserted by Tab in the code editors and the example source."),
+        ),
+    )
+}
+
+fn charts_page() -> SettingPage {
+    let animation = SettingField::switch(
+        |cx| Preferences::global(cx).chart_animation,
+        |animate, cx| update_preferences(cx, |preferences| preferences.chart_animation = animate),
+    )
+    .default_value(Preferences::default().chart_animation);
+
+    SettingPage::new("Charts").group(
+        SettingGroup::new().title("Live Data").item(
+            SettingItem::new("Chart Animation", animation)
+                .description("Keep adding points to the real time chart."),
+        ),
+    )
+}
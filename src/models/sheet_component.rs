@@ -0,0 +1,428 @@
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonGroup, ButtonVariants};
+use gpui_component::checkbox::Checkbox;
+use gpui_component::input::{Input, InputState};
+use gpui_component::notification::Notification;
+use gpui_component::*;
+
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+
+const PLACEMENTS: [(Placement, &str); 4] = [
+    (Placement::Left, "Left"),
+    (Placement::Right, "Right"),
+    (Placement::Top, "Top"),
+    (Placement::Bottom, "Bottom"),
+];
+
+const SIZES: [(f32, &str); 3] = [(280., "Small"), (400., "Medium"), (560., "Large")];
+
+/// Sheets of the steps example, opened one after the other
+const STEPS: usize = 3;
+
+/// Options of the sheets opened from the placement example
+#[derive(Clone, Copy)]
+struct SheetOptions {
+    size: usize,
+    overlay: bool,
+    overlay_closable: bool,
+    resizable: bool,
+}
+
+impl Default for SheetOptions {
+    fn default() -> Self {
+        Self {
+            size: 0,
+            overlay: true,
+            overlay_closable: true,
+            resizable: true,
+        }
+    }
+}
+
+/// Profile edited in the form sheet
+#[derive(Clone, Default)]
+struct Profile {
+    name: SharedString,
+    email: SharedString,
+}
+
+pub struct SheetComponentView {
+    options: SheetOptions,
+    profile: Profile,
+    name_input: Entity<InputState>,
+    email_input: Entity<InputState>,
+    form_error: Option<SharedString>,
+    close_count: usize,
+}
+
+impl ComponentMeta for SheetComponentView {
+    const NAME: &'static str = "Sheet";
+    const CATEGORY: ComponentCategory = ComponentCategory::Overlay;
+    const DESCRIPTION: &'static str = "A Sheet is a panel sliding in from an edge of the window, opened with `window.open_sheet_at`. \nIts size, overlay and whether clicking the overlay closes it are configurable, \nand like a dialog it can hold any content, such as a form with a footer.";
+    const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/sheet";
+
+    fn create(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            options: SheetOptions::default(),
+            profile: Profile::default(),
+            name_input: cx.new(|cx| InputState::new(window, cx).placeholder("Jane Doe")),
+            email_input: cx.new(|cx| InputState::new(window, cx).placeholder("jane@example.com")),
+            form_error: None,
+            close_count: 0,
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("placements", "Placements and Options")
+                .render(|this, _, cx| this.placements(cx)),
+            Self::example("close_behaviour", "Close Behaviour")
+                .render(|this, _, cx| this.close_behaviour(cx)),
+            Self::example("form_sheet", "Form Sheet").render(|this, _, cx| this.form_sheet(cx)),
+            Self::example("sheet_steps", "Sheet Steps")
+                .description("The window shows a single sheet, so a sheet opened from another one replaces it, and Back opens the previous one again.")
+                .render(|this, _, _| this.sheet_steps()),
+        ]
+    }
+}
+
+impl SheetComponentView {
+    // Example code for the Sheet component
+
+    fn placements(&self, cx: &mut Context<Self>) -> AnyElement {
+        let options = self.options;
+
+        v_flex()
+            .gap_3()
+            .child(
+                ButtonGroup::new("sheet-size")
+                    .outline()
+                    .small()
+                    .children(SIZES.into_iter().enumerate().map(|(ix, (size, label))| {
+                        Button::new(ix)
+                            .label(format!("{} ({}px)", label, size))
+                            .selected(ix == options.size)
+                    }))
+                    .on_click(cx.listener(|this, selected: &Vec<usize>, _, cx| {
+                        if let Some(&ix) = selected.first() {
+                            this.options.size = ix;
+                            cx.notify();
+                        }
+                    })),
+            )
+            .child(
+                h_flex()
+                    .gap_4()
+                    .child(
+                        Checkbox::new("sheet-overlay")
+                            .label("Overlay")
+                            .checked(options.overlay)
+                            .on_click(cx.listener(|this, checked, _, cx| {
+                                this.options.overlay = *checked;
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Checkbox::new("sheet-overlay-closable")
+                            .label("Click overlay to close")
+                            .checked(options.overlay_closable)
+                            .on_click(cx.listener(|this, checked, _, cx| {
+                                this.options.overlay_closable = *checked;
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Checkbox::new("sheet-resizable")
+                            .label("Resizable")
+                            .checked(options.resizable)
+                            .on_click(cx.listener(|this, checked, _, cx| {
+                                this.options.resizable = *checked;
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .children(PLACEMENTS.into_iter().map(|(placement, label)| {
+                        Button::new(label)
+                            .label(label)
+                            .on_click(move |_, window, cx| {
+                                let (size, size_label) = SIZES[options.size];
+                                window.open_sheet_at(placement, cx, move |sheet, _, cx| {
+                                    sheet
+                                        .title(format!("{} Sheet", label))
+                                        .size(px(size))
+                                        .overlay(options.overlay)
+                                        .overlay_closable(options.overlay_closable)
+                                        .resizable(options.resizable)
+                                        .gap_2()
+                                        .child(format!(
+                                            "This sheet slides in from the {} edge.",
+                                            label.to_lowercase()
+                                        ))
+                                        .child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(cx.theme().muted_foreground)
+                                                .child(format!(
+                                                    "Size: {} ({}px) · Overlay: {} · Resizable: {}",
+                                                    size_label,
+                                                    size,
+                                                    if options.overlay { "on" } else { "off" },
+                                                    if options.resizable { "yes" } else { "no" }
+                                                )),
+                                        )
+                                })
+                            })
+                    })),
+            )
+            .into_any_element()
+    }
+
+    fn close_behaviour(&self, cx: &mut Context<Self>) -> AnyElement {
+        let view = cx.entity().downgrade();
+
+        v_flex()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("sheet-modal")
+                            .label("Only Close Explicitly")
+                            .on_click(move |_, window, cx| {
+                                let view = view.clone();
+                                window.open_sheet(cx, move |sheet, _, cx| {
+                                    let view = view.clone();
+                                    sheet
+                                        .title("Unsaved Changes")
+                                        .overlay_closable(false)
+                                        .gap_2()
+                                        .child("Clicking the overlay does not close this sheet.")
+                                        .child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(cx.theme().muted_foreground)
+                                                .child(
+                                                    "Use the close button, press Escape or click Done.",
+                                                ),
+                                        )
+                                        .footer(
+                                            h_flex().justify_end().w_full().child(
+                                                Button::new("sheet-done")
+                                                    .primary()
+                                                    .label("Done")
+                                                    .on_click(|_, window, cx| {
+                                                        window.close_sheet(cx);
+                                                    }),
+                                            ),
+                                        )
+                                        .on_close(move |_, _, cx| {
+                                            _ = view.update(cx, |view, cx| {
+                                                view.close_count += 1;
+                                                cx.notify();
+                                            });
+                                        })
+                                })
+                            }),
+                    )
+                    .child(
+                        Button::new("sheet-no-overlay")
+                            .label("Without Overlay")
+                            .on_click(|_, window, cx| {
+                                window.open_sheet(cx, |sheet, _, _| {
+                                    sheet
+                                        .title("Without Overlay")
+                                        .overlay(false)
+                                        .child("The page behind this sheet stays visible.")
+                                })
+                            }),
+                    ),
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format!(
+                        "Dismissed with the close button or Escape {} times, Done is not counted",
+                        self.close_count
+                    )),
+            )
+            .into_any_element()
+    }
+
+    fn form_sheet(&self, cx: &mut Context<Self>) -> AnyElement {
+        let view = cx.entity().downgrade();
+
+        v_flex()
+            .gap_3()
+            .child(
+                Button::new("sheet-form")
+                    .label("Edit Profile")
+                    .on_click(cx.listener(move |this, _, window, cx| {
+                        // Start from the saved profile, edits are dropped on cancel
+                        let profile = this.profile.clone();
+                        this.name_input
+                            .update(cx, |input, cx| input.set_value(profile.name, window, cx));
+                        this.email_input
+                            .update(cx, |input, cx| input.set_value(profile.email, window, cx));
+                        this.form_error = None;
+
+                        let view = view.clone();
+                        window.open_sheet(cx, move |sheet, _, cx| {
+                            let Some(this) = view.upgrade() else {
+                                return sheet;
+                            };
+                            let this = this.read(cx);
+                            let submit_view = view.clone();
+
+                            sheet
+                                .title("Edit Profile")
+                                .size(px(400.))
+                                .gap_3()
+                                .child(
+                                    v_flex()
+                                        .gap_1()
+                                        .child(div().text_sm().child("Name"))
+                                        .child(Input::new(&this.name_input)),
+                                )
+                                .child(
+                                    v_flex()
+                                        .gap_1()
+                                        .child(div().text_sm().child("Email"))
+                                        .child(Input::new(&this.email_input)),
+                                )
+                                .children(this.form_error.clone().map(|error| {
+                                    div().text_sm().text_color(cx.theme().danger).child(error)
+                                }))
+                                .footer(
+                                    h_flex()
+                                        .gap_2()
+                                        .justify_end()
+                                        .w_full()
+                                        .child(
+                                            Button::new("sheet-form-cancel")
+                                                .label("Cancel")
+                                                .on_click(|_, window, cx| {
+                                                    window.close_sheet(cx);
+                                                }),
+                                        )
+                                        .child(
+                                            Button::new("sheet-form-submit")
+                                                .primary()
+                                                .label("Save")
+                                                .on_click(move |_, window, cx| {
+                                                    _ = submit_view.update(cx, |view, cx| {
+                                                        view.submit_profile(window, cx)
+                                                    });
+                                                }),
+                                        ),
+                                )
+                        });
+                        cx.notify();
+                    })),
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(if self.profile.name.is_empty() {
+                        "No profile saved yet.".to_string()
+                    } else {
+                        format!("Saved: {} <{}>", self.profile.name, self.profile.email)
+                    }),
+            )
+            .into_any_element()
+    }
+
+    /// Validate the form, the sheet stays open showing the error until the
+    /// profile is valid
+    fn submit_profile(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let name = self.name_input.read(cx).value().trim().to_string();
+        let email = self.email_input.read(cx).value().trim().to_string();
+
+        self.form_error = if name.is_empty() {
+            Some("Name is required.".into())
+        } else if !email.contains('@') {
+            Some("Enter a valid email address.".into())
+        } else {
+            None
+        };
+
+        if self.form_error.is_none() {
+            self.profile = Profile {
+                name: name.into(),
+                email: email.into(),
+            };
+            window.close_sheet(cx);
+            window.push_notification(Notification::success("Profile saved."), cx);
+        }
+        cx.notify();
+    }
+
+    fn sheet_steps(&self) -> AnyElement {
+        Button::new("sheet-steps")
+            .label("Open Sheet Steps")
+            .on_click(|_, window, cx| open_sheet_step(1, window, cx))
+            .into_any_element()
+    }
+}
+
+/// Open the sheet of step `depth`, replacing the sheet of the current step
+fn open_sheet_step(depth: usize, window: &mut Window, cx: &mut App) {
+    window.open_sheet(cx, move |sheet, _, cx| {
+        sheet
+            .title(format!("Sheet {} of {}", depth, STEPS))
+            .size(px(300. + 60. * depth as f32))
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_1()
+                    .text_sm()
+                    .children((1..=depth).map(|level| {
+                        div()
+                            .when(level < depth, |this| {
+                                this.text_color(cx.theme().muted_foreground)
+                            })
+                            .child(if level == 1 {
+                                format!("Sheet {}", level)
+                            } else {
+                                format!("› Sheet {}", level)
+                            })
+                    })),
+            )
+            .child(if depth < STEPS {
+                "Next replaces this sheet, Back from the next one opens it again."
+            } else {
+                "This is the last sheet."
+            })
+            .footer(
+                h_flex()
+                    .gap_2()
+                    .justify_end()
+                    .w_full()
+                    .when(depth > 1, |this| {
+                        this.child(
+                            Button::new("sheet-step-back")
+                                .label("Back")
+                                .icon(IconName::ArrowLeft)
+                                .on_click(move |_, window, cx| {
+                                    open_sheet_step(depth - 1, window, cx)
+                                }),
+                        )
+                    })
+                    .when(depth < STEPS, |this| {
+                        this.child(
+                            Button::new("sheet-step-next")
+                                .primary()
+                                .label("Next")
+                                .on_click(move |_, window, cx| {
+                                    open_sheet_step(depth + 1, window, cx)
+                                }),
+                        )
+                    }),
+            )
+    });
+}
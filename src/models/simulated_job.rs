@@ -0,0 +1,236 @@
+//! A simulated multi-stage background job, shared by the Progress, Spinner
+//! and Stepper pages so they all follow the same run.
+
+use std::time::Duration;
+
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::checkbox::Checkbox;
+use gpui_component::*;
+
+/// Stages of the job, in order
+pub const STAGES: [&str; 4] = ["Download", "Verify", "Install", "Clean up"];
+
+/// The stage failing halfway when errors are injected
+pub const FAILING_STAGE: usize = 2;
+
+const TICK: Duration = Duration::from_millis(100);
+
+/// Ticks to complete a stage, two seconds
+const TICKS_PER_STAGE: usize = 20;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum JobStatus {
+    Idle,
+    Running,
+    Paused,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl JobStatus {
+    /// Whether the job was started and has not ended yet
+    pub fn is_active(self) -> bool {
+        matches!(self, JobStatus::Running | JobStatus::Paused)
+    }
+}
+
+pub struct SimulatedJob {
+    status: JobStatus,
+    /// Ticks done over all stages
+    ticks: usize,
+    inject_error: bool,
+    _task: Option<Task<()>>,
+}
+
+/// The job of the whole gallery, so leaving a page doesn't stop it
+struct GlobalJob(Entity<SimulatedJob>);
+
+impl Global for GlobalJob {}
+
+impl SimulatedJob {
+    /// The job shared by the pages, created on first use
+    pub fn global(cx: &mut App) -> Entity<Self> {
+        if let Some(job) = cx.try_global::<GlobalJob>() {
+            return job.0.clone();
+        }
+        let job = cx.new(|_| Self {
+            status: JobStatus::Idle,
+            ticks: 0,
+            inject_error: false,
+            _task: None,
+        });
+        cx.set_global(GlobalJob(job.clone()));
+        job
+    }
+
+    pub fn status(&self) -> JobStatus {
+        self.status
+    }
+
+    /// Index of the current stage, `STAGES.len()` once completed
+    pub fn stage(&self) -> usize {
+        match self.status {
+            JobStatus::Completed => STAGES.len(),
+            _ => (self.ticks / TICKS_PER_STAGE).min(STAGES.len() - 1),
+        }
+    }
+
+    /// Progress of the current stage in percent
+    pub fn stage_progress(&self) -> f32 {
+        match self.status {
+            JobStatus::Completed => 100.,
+            _ => {
+                (self.ticks - self.stage() * TICKS_PER_STAGE) as f32 / TICKS_PER_STAGE as f32 * 100.
+            }
+        }
+    }
+
+    /// Progress of the whole job in percent
+    pub fn progress(&self) -> f32 {
+        self.ticks as f32 / (STAGES.len() * TICKS_PER_STAGE) as f32 * 100.
+    }
+
+    /// A line describing the status, e.g. "Install: 40%"
+    pub fn message(&self) -> String {
+        let stage = STAGES.get(self.stage()).copied().unwrap_or_default();
+        match self.status {
+            JobStatus::Idle => "Not started".to_string(),
+            JobStatus::Running => format!("{}: {:.0}%", stage, self.stage_progress()),
+            JobStatus::Paused => format!("Paused at {}: {:.0}%", stage, self.stage_progress()),
+            JobStatus::Completed => "All stages completed".to_string(),
+            JobStatus::Failed => format!("{} failed: checksum mismatch", stage),
+            JobStatus::Cancelled => format!("Cancelled at {}", stage),
+        }
+    }
+
+    pub fn inject_error(&self) -> bool {
+        self.inject_error
+    }
+
+    pub fn set_inject_error(&mut self, inject_error: bool, cx: &mut Context<Self>) {
+        self.inject_error = inject_error;
+        cx.notify();
+    }
+
+    /// Start the job from the first stage, restarting it if it ran before
+    pub fn start(&mut self, cx: &mut Context<Self>) {
+        self.ticks = 0;
+        self.run(cx);
+    }
+
+    pub fn pause(&mut self, cx: &mut Context<Self>) {
+        if self.status == JobStatus::Running {
+            // Dropping the task stops it at the next await
+            self._task = None;
+            self.status = JobStatus::Paused;
+            cx.notify();
+        }
+    }
+
+    pub fn resume(&mut self, cx: &mut Context<Self>) {
+        if self.status == JobStatus::Paused {
+            self.run(cx);
+        }
+    }
+
+    pub fn cancel(&mut self, cx: &mut Context<Self>) {
+        if self.status.is_active() {
+            self._task = None;
+            self.status = JobStatus::Cancelled;
+            cx.notify();
+        }
+    }
+
+    fn run(&mut self, cx: &mut Context<Self>) {
+        self.status = JobStatus::Running;
+        self._task = Some(cx.spawn(async |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            loop {
+                cx.background_executor().timer(TICK).await;
+
+                let running = this.update(cx, |job, cx| job.tick(cx)).unwrap_or(false);
+                if !running {
+                    break;
+                }
+            }
+        }));
+        cx.notify();
+    }
+
+    /// Advance the job by a tick, returns whether it is still running
+    fn tick(&mut self, cx: &mut Context<Self>) -> bool {
+        self.ticks += 1;
+        if self.inject_error && self.stage() == FAILING_STAGE && self.stage_progress() >= 50. {
+            self.status = JobStatus::Failed;
+        } else if self.ticks == STAGES.len() * TICKS_PER_STAGE {
+            self.status = JobStatus::Completed;
+        }
+        cx.notify();
+
+        self.status == JobStatus::Running
+    }
+}
+
+/// Buttons to start, pause, resume and cancel the job, and a checkbox
+/// making it fail
+pub fn job_controls(job: &Entity<SimulatedJob>, cx: &App) -> impl IntoElement {
+    let status = job.read(cx).status();
+    let inject_error = job.read(cx).inject_error();
+
+    h_flex()
+        .gap_2()
+        .flex_wrap()
+        .child(
+            Button::new("job-start")
+                .primary()
+                .small()
+                .label(if status == JobStatus::Idle {
+                    "Start"
+                } else {
+                    "Restart"
+                })
+                .disabled(status.is_active())
+                .on_click({
+                    let job = job.clone();
+                    move |_, _, cx| job.update(cx, |job, cx| job.start(cx))
+                }),
+        )
+        .child(if status == JobStatus::Paused {
+            Button::new("job-resume").small().label("Resume").on_click({
+                let job = job.clone();
+                move |_, _, cx| job.update(cx, |job, cx| job.resume(cx))
+            })
+        } else {
+            Button::new("job-pause")
+                .small()
+                .label("Pause")
+                .disabled(status != JobStatus::Running)
+                .on_click({
+                    let job = job.clone();
+                    move |_, _, cx| job.update(cx, |job, cx| job.pause(cx))
+                })
+        })
+        .child(
+            Button::new("job-cancel")
+                .small()
+                .danger()
+                .label("Cancel")
+                .disabled(!status.is_active())
+                .on_click({
+                    let job = job.clone();
+                    move |_, _, cx| job.update(cx, |job, cx| job.cancel(cx))
+                }),
+        )
+        .child(
+            Checkbox::new("job-inject-error")
+                .label(format!("Fail at {}", STAGES[FAILING_STAGE]))
+                .checked(inject_error)
+                .on_click({
+                    let job = job.clone();
+                    move |checked, _, cx| {
+                        job.update(cx, |job, cx| job.set_inject_error(*checked, cx))
+                    }
+                }),
+        )
+}
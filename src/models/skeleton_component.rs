@@ -3,61 +3,56 @@ use gpui_component::checkbox::Checkbox;
 use gpui_component::skeleton::Skeleton;
 use gpui_component::*;
 
-use crate::models::{ComponentMeta, subtitle};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
 
 pub struct SkeletonComponentView {
     is_loading: bool,
 }
 
-impl SkeletonComponentView {
-    pub fn new() -> Self {
-        Self { is_loading: true }
-    }
-}
-
 impl ComponentMeta for SkeletonComponentView {
+    const NAME: &'static str = "Skeleton";
+    const CATEGORY: ComponentCategory = ComponentCategory::Feedback;
     const DESCRIPTION: &'static str = "The Skeleton component displays animated placeholder content while actual content is loading. \nIt provides visual feedback to users that content is being loaded and helps maintain layout structure during loading states.";
     const LINK: &'static str =
         "https://longbridge.github.io/gpui-component/docs/components/skeleton";
-}
 
-impl Render for SkeletonComponentView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .w_full()
-            .max_w_96()
-            .child(subtitle("Basic Skeleton"))
-            .child(self.basic_skeleton())
-            .child(subtitle("Text Line Skeleton"))
-            .child(self.text_line_skeleton())
-            .child(subtitle("Circle Skeleton"))
-            .child(self.circle_skeleton())
-            .child(subtitle("Rectangle Skeleton"))
-            .child(self.rectangle_skeleton())
-            .child(subtitle("Different Shapes"))
-            .child(self.different_shapes())
-            .child(subtitle("Secondary Variant"))
-            .child(self.secondary_variant())
-            .child(subtitle("Sizes"))
-            .child(self.sizes())
-            .child(subtitle("Loading Profile Card"))
-            .child(self.loading_profile_card(_cx))
-            .child(subtitle("Loading Article List"))
-            .child(self.loading_article_list())
-            .child(subtitle("Loading Table Rows"))
-            .child(self.loading_table_rows(_cx))
-            .child(subtitle("Loading Button States"))
-            .child(self.loading_button_states())
-            .child(subtitle("Loading Form Fields"))
-            .child(self.loading_form_fields())
-            .child(subtitle("Conditional Loading"))
-            .child(self.conditional_loading(_cx))
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self { is_loading: true }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_skeleton", "Basic Skeleton")
+                .render(|this, _, _| this.basic_skeleton()),
+            Self::example("text_line_skeleton", "Text Line Skeleton")
+                .render(|this, _, _| this.text_line_skeleton()),
+            Self::example("circle_skeleton", "Circle Skeleton")
+                .render(|this, _, _| this.circle_skeleton()),
+            Self::example("rectangle_skeleton", "Rectangle Skeleton")
+                .render(|this, _, _| this.rectangle_skeleton()),
+            Self::example("different_shapes", "Different Shapes")
+                .render(|this, _, _| this.different_shapes()),
+            Self::example("secondary_variant", "Secondary Variant")
+                .render(|this, _, _| this.secondary_variant()),
+            Self::example("sizes", "Sizes").render(|this, _, _| this.sizes()),
+            Self::example("loading_profile_card", "Loading Profile Card")
+                .render(|this, _, cx| this.loading_profile_card(cx)),
+            Self::example("loading_article_list", "Loading Article List")
+                .render(|this, _, _| this.loading_article_list()),
+            Self::example("loading_table_rows", "Loading Table Rows")
+                .render(|this, _, cx| this.loading_table_rows(cx)),
+            Self::example("loading_button_states", "Loading Button States")
+                .render(|this, _, _| this.loading_button_states()),
+            Self::example("loading_form_fields", "Loading Form Fields")
+                .render(|this, _, _| this.loading_form_fields()),
+            Self::example("conditional_loading", "Conditional Loading")
+                .render(|this, _, cx| this.conditional_loading(cx)),
+        ]
     }
 }
 
 impl SkeletonComponentView {
-    /// Example code for the Skeleton component
+    // Example code for the Skeleton component
 
     fn basic_skeleton(&self) -> AnyElement {
         Skeleton::new().into_any_element()
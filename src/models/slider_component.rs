@@ -0,0 +1,335 @@
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::input::{InputEvent, InputState, NumberInput, NumberInputEvent, StepAction};
+use gpui_component::slider::{Slider, SliderEvent, SliderState};
+use gpui_component::*;
+
+use crate::models::{ComponentCategory, ComponentMeta, Example, value_label};
+
+/// Ticks of the stepped slider, one for every step
+const TICKS: [&str; 5] = ["XS", "S", "M", "L", "XL"];
+
+/// Vertical sliders of the equalizer, with their default gain in dB
+const BANDS: [(&str, f32); 5] = [
+    ("60 Hz", 4.),
+    ("250 Hz", 1.),
+    ("1 kHz", -2.),
+    ("4 kHz", 2.),
+    ("16 kHz", 5.),
+];
+
+/// Number of bars of the volume meter
+const METER_BARS: usize = 10;
+
+pub struct SliderComponentView {
+    basic: Entity<SliderState>,
+    range: Entity<SliderState>,
+    bands: Vec<Entity<SliderState>>,
+    stepped: Entity<SliderState>,
+    bound: Entity<SliderState>,
+    bound_input: Entity<InputState>,
+    disabled: Entity<SliderState>,
+    volume: Entity<SliderState>,
+    muted: bool,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ComponentMeta for SliderComponentView {
+    const NAME: &'static str = "Slider";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataEntry;
+    const DESCRIPTION: &'static str = "A Slider selects a value, or a range of values with two thumbs, by dragging along a bar. \nIts value lives in a SliderState with a minimum, maximum and step, \nwhich emits a change event while dragging.";
+    const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/slider";
+
+    fn create(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let basic = cx.new(|_| SliderState::new().default_value(40.));
+        let range = cx.new(|_| {
+            SliderState::new()
+                .min(0.)
+                .max(1000.)
+                .step(10.)
+                .default_value((200., 650.))
+        });
+        let bands = BANDS
+            .into_iter()
+            .map(|(_, gain)| {
+                cx.new(|_| {
+                    SliderState::new()
+                        .min(-12.)
+                        .max(12.)
+                        .step(0.5)
+                        .default_value(gain)
+                })
+            })
+            .collect::<Vec<_>>();
+        let stepped = cx.new(|_| {
+            SliderState::new()
+                .min(0.)
+                .max((TICKS.len() - 1) as f32)
+                .step(1.)
+                .default_value(2.)
+        });
+        let bound = cx.new(|_| SliderState::new().default_value(25.));
+        let bound_input = cx.new(|cx| InputState::new(window, cx).default_value("25"));
+        let disabled = cx.new(|_| SliderState::new().default_value(60.));
+        let volume = cx.new(|_| SliderState::new().default_value(70.));
+
+        let mut _subscriptions = [&basic, &range, &stepped, &volume]
+            .into_iter()
+            .chain(&bands)
+            .map(|state| cx.subscribe(state, |_, _, _: &SliderEvent, cx| cx.notify()))
+            .collect::<Vec<_>>();
+        _subscriptions.extend([
+            cx.subscribe_in(&bound, window, Self::on_bound_slider_event),
+            cx.subscribe_in(&bound_input, window, Self::on_bound_input_event),
+            cx.subscribe_in(&bound_input, window, Self::on_bound_input_step),
+        ]);
+
+        Self {
+            basic,
+            range,
+            bands,
+            stepped,
+            bound,
+            bound_input,
+            disabled,
+            volume,
+            muted: false,
+            _subscriptions,
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_slider", "Basic Slider")
+                .render(|this, _, cx| this.basic_slider(cx)),
+            Self::example("range_slider", "Range Slider")
+                .render(|this, _, cx| this.range_slider(cx)),
+            Self::example("vertical_sliders", "Vertical Sliders")
+                .render(|this, _, cx| this.vertical_sliders(cx)),
+            Self::example("steps_and_ticks", "Steps and Tick Marks")
+                .render(|this, _, cx| this.steps_and_ticks(cx)),
+            Self::example("bound_to_number_input", "Bound to a Number Input")
+                .render(|this, _, _| this.bound_to_number_input()),
+            Self::example("disabled_slider", "Disabled Slider")
+                .render(|this, _, _| this.disabled_slider()),
+            Self::example("volume_control", "Volume Control")
+                .render(|this, _, cx| this.volume_control(cx)),
+        ]
+    }
+}
+
+impl SliderComponentView {
+    // Example code for the Slider component
+
+    fn basic_slider(&self, cx: &mut Context<Self>) -> AnyElement {
+        v_flex()
+            .gap_2()
+            .child(Slider::new(&self.basic))
+            .child(value_label(
+                format!("Value: {}", self.basic.read(cx).value()),
+                cx,
+            ))
+            .into_any_element()
+    }
+
+    fn range_slider(&self, cx: &mut Context<Self>) -> AnyElement {
+        let value = self.range.read(cx).value();
+
+        v_flex()
+            .gap_2()
+            .child(Slider::new(&self.range))
+            .child(value_label(
+                format!("Price: ${} – ${}", value.start(), value.end()),
+                cx,
+            ))
+            .into_any_element()
+    }
+
+    fn vertical_sliders(&self, cx: &mut Context<Self>) -> AnyElement {
+        h_flex()
+            .gap_6()
+            .children(
+                BANDS
+                    .into_iter()
+                    .zip(&self.bands)
+                    .map(|((label, _), state)| {
+                        let gain = state.read(cx).value().start();
+                        v_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(value_label(format!("{:+.1} dB", gain), cx))
+                            .child(Slider::new(state).vertical().h(px(140.)))
+                            .child(div().text_xs().child(label))
+                    }),
+            )
+            .into_any_element()
+    }
+
+    fn steps_and_ticks(&self, cx: &mut Context<Self>) -> AnyElement {
+        let selected = self.stepped.read(cx).value().start() as usize;
+
+        v_flex()
+            .gap_1()
+            .child(Slider::new(&self.stepped))
+            .child(
+                // The thumb centers stop at the ends of the bar, so do the ticks
+                h_flex()
+                    .justify_between()
+                    .children(TICKS.into_iter().enumerate().map(|(ix, label)| {
+                        v_flex()
+                            .w_6()
+                            .items_center()
+                            .gap_0p5()
+                            .child(div().w(px(1.)).h_1p5().bg(cx.theme().border))
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .map(|this| {
+                                        if ix == selected {
+                                            this.font_semibold()
+                                        } else {
+                                            this.text_color(cx.theme().muted_foreground)
+                                        }
+                                    })
+                                    .child(label),
+                            )
+                    })),
+            )
+            .into_any_element()
+    }
+
+    fn bound_to_number_input(&self) -> AnyElement {
+        h_flex()
+            .gap_4()
+            .child(div().flex_1().child(Slider::new(&self.bound)))
+            .child(div().w_32().child(NumberInput::new(&self.bound_input)))
+            .into_any_element()
+    }
+
+    fn on_bound_slider_event(
+        &mut self,
+        _: &Entity<SliderState>,
+        event: &SliderEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let SliderEvent::Change(value) = event;
+        let text = value.start().to_string();
+        self.bound_input
+            .update(cx, |input, cx| input.set_value(text, window, cx));
+    }
+
+    fn on_bound_input_event(
+        &mut self,
+        input: &Entity<InputState>,
+        event: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        match event {
+            InputEvent::Change => {
+                if let Ok(value) = input.read(cx).value().trim().parse::<f32>() {
+                    self.set_bound(value, false, window, cx);
+                }
+            }
+            // Show the clamped value once editing is done
+            InputEvent::Blur | InputEvent::PressEnter { .. } => {
+                let value = self.bound.read(cx).value().start();
+                self.set_bound(value, true, window, cx);
+            }
+            InputEvent::Focus => {}
+        }
+    }
+
+    fn on_bound_input_step(
+        &mut self,
+        _: &Entity<InputState>,
+        event: &NumberInputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let NumberInputEvent::Step(action) = event;
+        let value = self.bound.read(cx).value().start();
+        let value = match action {
+            StepAction::Increment => value + 1.,
+            StepAction::Decrement => value - 1.,
+        };
+        self.set_bound(value, true, window, cx);
+    }
+
+    /// Move the slider to the value, and update the text of the input unless
+    /// it is being edited
+    fn set_bound(
+        &mut self,
+        value: f32,
+        update_input: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let value = value.clamp(0., 100.).round();
+        self.bound
+            .update(cx, |slider, cx| slider.set_value(value, window, cx));
+        if update_input {
+            self.bound_input.update(cx, |input, cx| {
+                input.set_value(value.to_string(), window, cx)
+            });
+        }
+    }
+
+    fn disabled_slider(&self) -> AnyElement {
+        Slider::new(&self.disabled)
+            .disabled(true)
+            .into_any_element()
+    }
+
+    fn volume_control(&self, cx: &mut Context<Self>) -> AnyElement {
+        let volume = self.volume.read(cx).value().start();
+        let level = if self.muted { 0. } else { volume };
+        let lit_bars = (level / 100. * METER_BARS as f32).ceil() as usize;
+
+        h_flex()
+            .gap_4()
+            .child(
+                Button::new("mute")
+                    .label(if self.muted { "Unmute" } else { "Mute" })
+                    .small()
+                    .when(self.muted, |this| this.primary())
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.muted = !this.muted;
+                        cx.notify();
+                    })),
+            )
+            .child(
+                div().flex_1().child(
+                    Slider::new(&self.volume)
+                        .when(self.muted, |this| this.bg(cx.theme().muted_foreground)),
+                ),
+            )
+            .child(
+                h_flex()
+                    .gap_0p5()
+                    .items_end()
+                    .h_5()
+                    .children((0..METER_BARS).map(|ix| {
+                        let color = match ix {
+                            _ if ix >= lit_bars => cx.theme().muted,
+                            8.. => cx.theme().danger,
+                            6.. => cx.theme().warning,
+                            _ => cx.theme().success,
+                        };
+                        div()
+                            .w_1()
+                            .h(relative((ix + 1) as f32 / METER_BARS as f32))
+                            .rounded_sm()
+                            .bg(color)
+                    })),
+            )
+            .child(div().w_12().text_right().text_sm().child(if self.muted {
+                "Muted".to_string()
+            } else {
+                format!("{:.0}%", volume)
+            }))
+            .into_any_element()
+    }
+}
@@ -0,0 +1,138 @@
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::spinner::Spinner;
+use gpui_component::*;
+
+use crate::models::simulated_job::{JobStatus, STAGES, SimulatedJob, job_controls};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+
+pub struct SpinnerComponentView {
+    job: Entity<SimulatedJob>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ComponentMeta for SpinnerComponentView {
+    const NAME: &'static str = "Spinner";
+    const CATEGORY: ComponentCategory = ComponentCategory::Feedback;
+    const DESCRIPTION: &'static str = "A Spinner is a rotating icon showing that work is in progress \nwhen its amount or duration is unknown. Its size, color and icon can be changed.";
+    const LINK: &'static str =
+        "https://longbridge.github.io/gpui-component/docs/components/spinner";
+
+    fn create(_window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let job = SimulatedJob::global(cx);
+        let _subscriptions = vec![cx.observe(&job, |_, _, cx| cx.notify())];
+
+        Self {
+            job,
+            _subscriptions,
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("sizes", "Sizes").render(|this, _, _| this.sizes()),
+            Self::example("colors_and_icons", "Colors and Icons")
+                .render(|this, _, cx| this.colors_and_icons(cx)),
+            Self::example("loading_button", "Loading Button")
+                .render(|this, _, cx| this.loading_button(cx)),
+            Self::example("background_job", "Background Job")
+                .render(|this, _, cx| this.background_job(cx)),
+        ]
+    }
+}
+
+impl SpinnerComponentView {
+    // Example code for the Spinner component
+
+    fn sizes(&self) -> AnyElement {
+        h_flex()
+            .gap_4()
+            .items_center()
+            .child(Spinner::new().xsmall())
+            .child(Spinner::new().small())
+            .child(Spinner::new())
+            .child(Spinner::new().large())
+            .into_any_element()
+    }
+
+    fn colors_and_icons(&self, cx: &mut Context<Self>) -> AnyElement {
+        h_flex()
+            .gap_4()
+            .child(Spinner::new().color(cx.theme().primary))
+            .child(Spinner::new().color(cx.theme().success))
+            .child(Spinner::new().color(cx.theme().danger))
+            .child(Spinner::new().icon(IconName::LoaderCircle))
+            .into_any_element()
+    }
+
+    fn loading_button(&self, cx: &mut Context<Self>) -> AnyElement {
+        let status = self.job.read(cx).status();
+        let running = status == JobStatus::Running;
+
+        h_flex()
+            .gap_3()
+            .child(
+                Button::new("run-job")
+                    .primary()
+                    .label(match status {
+                        JobStatus::Running => "Running…",
+                        JobStatus::Paused => "Resume job",
+                        _ => "Run job",
+                    })
+                    .loading(running)
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        // A paused job goes on where it stopped, like on the
+                        // other pages following it
+                        this.job.update(cx, |job, cx| match job.status() {
+                            JobStatus::Paused => job.resume(cx),
+                            _ => job.start(cx),
+                        });
+                    })),
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child("A loading button ignores clicks until the job stops."),
+            )
+            .into_any_element()
+    }
+
+    fn background_job(&self, cx: &mut Context<Self>) -> AnyElement {
+        let job = self.job.read(cx);
+        let status = job.status();
+        // The spinner tells that something happens, not how much is left
+        let indicator = match status {
+            JobStatus::Running => Spinner::new().small().into_any_element(),
+            JobStatus::Completed => Icon::new(IconName::CircleCheck)
+                .small()
+                .text_color(cx.theme().success)
+                .into_any_element(),
+            JobStatus::Failed => Icon::new(IconName::CircleX)
+                .small()
+                .text_color(cx.theme().danger)
+                .into_any_element(),
+            JobStatus::Idle | JobStatus::Paused | JobStatus::Cancelled => Icon::new(IconName::Dash)
+                .small()
+                .text_color(cx.theme().muted_foreground)
+                .into_any_element(),
+        };
+        let title = match status {
+            JobStatus::Running => {
+                format!(
+                    "{} ({} of {})",
+                    STAGES[job.stage()],
+                    job.stage() + 1,
+                    STAGES.len()
+                )
+            }
+            _ => job.message(),
+        };
+
+        v_flex()
+            .gap_3()
+            .child(job_controls(&self.job, cx))
+            .child(h_flex().gap_2().text_sm().child(indicator).child(title))
+            .into_any_element()
+    }
+}
@@ -0,0 +1,253 @@
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::*;
+
+use crate::models::simulated_job::{JobStatus, STAGES, SimulatedJob, job_controls};
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+
+const CHECKOUT_STEPS: [&str; 4] = ["Cart", "Shipping", "Payment", "Review"];
+
+const ORDER_STEPS: [(&str, &str); 4] = [
+    ("Order placed", "We received your order on May 2."),
+    ("Packed", "Your items were packed at the warehouse."),
+    ("Shipped", "The parcel is on its way, tracking 1Z 999 AA1."),
+    ("Delivered", "Expected on May 6."),
+];
+
+#[derive(Clone, Copy, PartialEq)]
+enum StepState {
+    Completed,
+    Current,
+    Failed,
+    Pending,
+}
+
+impl StepState {
+    /// State of the step at `ix` when the step at `current` is being done
+    fn at(ix: usize, current: usize) -> Self {
+        match ix {
+            _ if ix < current => StepState::Completed,
+            _ if ix == current => StepState::Current,
+            _ => StepState::Pending,
+        }
+    }
+}
+
+pub struct StepperComponentView {
+    checkout_step: usize,
+    job: Entity<SimulatedJob>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ComponentMeta for StepperComponentView {
+    const NAME: &'static str = "Stepper";
+    const CATEGORY: ComponentCategory = ComponentCategory::Feedback;
+    const DESCRIPTION: &'static str = "A Stepper shows the steps of a process and which of them are done, current, pending or failed. \ngpui-component has no stepper yet, this one is built from flex rows, icons and dividers.";
+    const LINK: &'static str = "https://github.com/longbridge/gpui-component";
+
+    fn create(_window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let job = SimulatedJob::global(cx);
+        let _subscriptions = vec![cx.observe(&job, |_, _, cx| cx.notify())];
+
+        Self {
+            checkout_step: 1,
+            job,
+            _subscriptions,
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("horizontal_stepper", "Horizontal Stepper")
+                .render(|this, _, cx| this.horizontal_stepper(cx)),
+            Self::example("vertical_stepper", "Vertical Stepper with Descriptions")
+                .render(|this, _, cx| this.vertical_stepper(cx)),
+            Self::example("background_job", "Background Job")
+                .render(|this, _, cx| this.background_job(cx)),
+        ]
+    }
+}
+
+impl StepperComponentView {
+    // Example code for the Stepper
+
+    fn horizontal_stepper(&self, cx: &mut Context<Self>) -> AnyElement {
+        let current = self.checkout_step;
+        let steps = CHECKOUT_STEPS
+            .into_iter()
+            .enumerate()
+            .map(|(ix, label)| (label, StepState::at(ix, current)));
+
+        v_flex()
+            .gap_4()
+            .child(steps_row(steps, cx))
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("checkout-back")
+                            .small()
+                            .label("Back")
+                            .disabled(current == 0)
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.checkout_step -= 1;
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Button::new("checkout-next")
+                            .small()
+                            .primary()
+                            .label(if current + 1 >= CHECKOUT_STEPS.len() {
+                                "Place order"
+                            } else {
+                                "Next"
+                            })
+                            .disabled(current == CHECKOUT_STEPS.len())
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.checkout_step += 1;
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    fn vertical_stepper(&self, cx: &mut Context<Self>) -> AnyElement {
+        let current = 2;
+
+        v_flex()
+            .children(
+                ORDER_STEPS
+                    .into_iter()
+                    .enumerate()
+                    .map(|(ix, (title, description))| {
+                        let state = StepState::at(ix, current);
+                        let last = ix + 1 == ORDER_STEPS.len();
+
+                        h_flex()
+                            .gap_3()
+                            .items_start()
+                            .child(
+                                v_flex()
+                                    .items_center()
+                                    .h_full()
+                                    .child(step_marker(ix, state, cx))
+                                    .when(!last, |this| {
+                                        // Colored by the step it leads to, like in `steps_row`
+                                        let next = StepState::at(ix + 1, current);
+                                        this.child(connector(next, cx).w(px(1.)).h_8())
+                                    }),
+                            )
+                            .child(
+                                v_flex().pb_2().child(step_label(title, state, cx)).child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(description),
+                                ),
+                            )
+                    }),
+            )
+            .into_any_element()
+    }
+
+    fn background_job(&self, cx: &mut Context<Self>) -> AnyElement {
+        let job = self.job.read(cx);
+        let status = job.status();
+        let stage = job.stage();
+        let steps = STAGES.into_iter().enumerate().map(|(ix, label)| {
+            let state = match StepState::at(ix, stage) {
+                StepState::Current if status == JobStatus::Failed => StepState::Failed,
+                StepState::Current if status == JobStatus::Idle => StepState::Pending,
+                state => state,
+            };
+            (label, state)
+        });
+        let color = match status {
+            JobStatus::Failed => cx.theme().danger,
+            JobStatus::Completed => cx.theme().success,
+            _ => cx.theme().muted_foreground,
+        };
+
+        v_flex()
+            .gap_4()
+            .child(job_controls(&self.job, cx))
+            .child(steps_row(steps, cx))
+            .child(div().text_sm().text_color(color).child(job.message()))
+            .into_any_element()
+    }
+}
+
+/// Steps side by side, joined by lines
+fn steps_row(steps: impl Iterator<Item = (&'static str, StepState)>, cx: &App) -> Div {
+    h_flex()
+        .w_full()
+        .gap_2()
+        .children(steps.enumerate().map(|(ix, (label, state))| {
+            h_flex()
+                .gap_2()
+                .when(ix > 0, |this| this.flex_1())
+                .when(ix > 0, |this| {
+                    this.child(connector(state, cx).h(px(1.)).flex_1())
+                })
+                .child(step_marker(ix, state, cx))
+                .child(step_label(label, state, cx))
+        }))
+}
+
+/// The circle of a step, with its number or an icon for its state
+fn step_marker(ix: usize, state: StepState, cx: &App) -> Div {
+    let theme = cx.theme();
+    let marker = div()
+        .flex()
+        .flex_none()
+        .items_center()
+        .justify_center()
+        .size_6()
+        .rounded_full()
+        .border_1()
+        .text_xs();
+
+    match state {
+        StepState::Completed => marker
+            .border_color(theme.primary)
+            .bg(theme.primary)
+            .text_color(theme.primary_foreground)
+            .child(Icon::new(IconName::Check).xsmall()),
+        StepState::Current => marker
+            .border_color(theme.primary)
+            .text_color(theme.primary)
+            .child((ix + 1).to_string()),
+        StepState::Failed => marker
+            .border_color(theme.danger)
+            .bg(theme.danger)
+            .text_color(theme.danger_foreground)
+            .child(Icon::new(IconName::Close).xsmall()),
+        StepState::Pending => marker
+            .border_color(theme.border)
+            .text_color(theme.muted_foreground)
+            .child((ix + 1).to_string()),
+    }
+}
+
+fn step_label(label: &'static str, state: StepState, cx: &App) -> Div {
+    div()
+        .text_sm()
+        .map(|this| match state {
+            StepState::Current => this.font_semibold(),
+            StepState::Failed => this.text_color(cx.theme().danger),
+            StepState::Pending => this.text_color(cx.theme().muted_foreground),
+            StepState::Completed => this,
+        })
+        .child(label)
+}
+
+/// The line leading to a step, highlighted once the step is reached
+fn connector(state: StepState, cx: &App) -> Div {
+    div().bg(match state {
+        StepState::Pending => cx.theme().border,
+        _ => cx.theme().primary,
+    })
+}
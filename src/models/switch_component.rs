@@ -0,0 +1,178 @@
+use gpui::*;
+use gpui_component::switch::Switch;
+use gpui_component::*;
+
+use crate::models::{ComponentCategory, ComponentMeta, Example, value_label};
+
+const CHANNELS: [(&str, &str); 3] = [
+    ("Email", "A daily digest of the activity."),
+    ("Push", "Mentions and direct messages right away."),
+    ("SMS", "Only security alerts."),
+];
+
+pub struct SwitchComponentView {
+    airplane_mode: bool,
+    channels: [bool; 3],
+    small: bool,
+    medium: bool,
+}
+
+impl ComponentMeta for SwitchComponentView {
+    const NAME: &'static str = "Switch";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataEntry;
+    const DESCRIPTION: &'static str = "A Switch turns a setting on or off, taking effect right away. \nSupports labels, tooltips, disabled state, and two sizes.";
+    const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/switch";
+
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self {
+            airplane_mode: false,
+            channels: [true, true, false],
+            small: true,
+            medium: false,
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("controlled_switch", "Controlled Switch")
+                .render(|this, _, cx| this.controlled_switch(cx)),
+            Self::example("settings_list", "Settings List")
+                .render(|this, _, cx| this.settings_list(cx)),
+            Self::example("sizes", "Sizes").render(|this, _, cx| this.sizes(cx)),
+            Self::example("disabled_state", "Disabled State")
+                .render(|this, _, _| this.disabled_state()),
+        ]
+    }
+}
+
+impl SwitchComponentView {
+    // Example code for the Switch component
+
+    fn controlled_switch(&self, cx: &mut Context<Self>) -> AnyElement {
+        v_flex()
+            .gap_3()
+            .child(
+                Switch::new("airplane-mode")
+                    .label("Airplane mode")
+                    .checked(self.airplane_mode)
+                    .on_click(cx.listener(|this, checked: &bool, _, cx| {
+                        this.airplane_mode = *checked;
+                        cx.notify();
+                    })),
+            )
+            .child(value_label(
+                format!("Airplane mode: {}", on_off(self.airplane_mode)),
+                cx,
+            ))
+            .into_any_element()
+    }
+
+    fn settings_list(&self, cx: &mut Context<Self>) -> AnyElement {
+        let enabled = CHANNELS
+            .iter()
+            .zip(self.channels)
+            .filter(|(_, checked)| *checked)
+            .map(|((name, _), _)| *name)
+            .collect::<Vec<_>>();
+
+        v_flex()
+            .gap_3()
+            .w_full()
+            .max_w(px(420.))
+            .children(
+                CHANNELS
+                    .into_iter()
+                    .enumerate()
+                    .map(|(ix, (name, description))| {
+                        h_flex()
+                            .justify_between()
+                            .gap_4()
+                            .child(
+                                v_flex().child(div().text_sm().child(name)).child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(description),
+                                ),
+                            )
+                            .child(
+                                Switch::new(("channel", ix))
+                                    .checked(self.channels[ix])
+                                    .on_click(cx.listener(move |this, checked: &bool, _, cx| {
+                                        this.channels[ix] = *checked;
+                                        cx.notify();
+                                    })),
+                            )
+                    }),
+            )
+            .child(value_label(
+                if enabled.is_empty() {
+                    "Notify by: nothing".to_string()
+                } else {
+                    format!("Notify by: {}", enabled.join(", "))
+                },
+                cx,
+            ))
+            .into_any_element()
+    }
+
+    fn sizes(&self, cx: &mut Context<Self>) -> AnyElement {
+        v_flex()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_6()
+                    .child(
+                        Switch::new("small-switch")
+                            .small()
+                            .label("Small")
+                            .checked(self.small)
+                            .on_click(cx.listener(|this, checked: &bool, _, cx| {
+                                this.small = *checked;
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Switch::new("medium-switch")
+                            .label("Medium")
+                            .checked(self.medium)
+                            .on_click(cx.listener(|this, checked: &bool, _, cx| {
+                                this.medium = *checked;
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .child(value_label(
+                format!(
+                    "Small: {} · Medium: {}",
+                    on_off(self.small),
+                    on_off(self.medium)
+                ),
+                cx,
+            ))
+            .into_any_element()
+    }
+
+    fn disabled_state(&self) -> AnyElement {
+        h_flex()
+            .gap_6()
+            .child(
+                Switch::new("disabled-off")
+                    .label("Off")
+                    .disabled(true)
+                    .tooltip("Managed by your organization"),
+            )
+            .child(
+                Switch::new("disabled-on")
+                    .label("On")
+                    .checked(true)
+                    .disabled(true)
+                    .tooltip("Managed by your organization"),
+            )
+            .into_any_element()
+    }
+}
+
+fn on_off(checked: bool) -> &'static str {
+    if checked { "On" } else { "Off" }
+}
@@ -0,0 +1,584 @@
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::menu::{PopupMenu, PopupMenuItem};
+use gpui_component::table::{Column, ColumnSort, Table, TableDelegate, TableState};
+use gpui_component::*;
+
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+
+/// Rows of the virtualized example
+const LARGE_ROWS: usize = 100_000;
+
+const SECTORS: [&str; 8] = [
+    "Technology",
+    "Healthcare",
+    "Financials",
+    "Energy",
+    "Industrials",
+    "Utilities",
+    "Materials",
+    "Consumer",
+];
+
+const NAME_PREFIXES: [&str; 10] = [
+    "Apex", "Blue", "Crest", "Delta", "Echo", "Nova", "Orbit", "Pine", "Quant", "Vertex",
+];
+
+const NAME_SUFFIXES: [&str; 6] = [
+    "Holdings",
+    "Systems",
+    "Labs",
+    "Group",
+    "Industries",
+    "Partners",
+];
+
+/// A generated stock quote, one row of the tables
+#[derive(Clone)]
+struct Stock {
+    id: usize,
+    symbol: SharedString,
+    name: SharedString,
+    sector: &'static str,
+    price: f64,
+    change: f64,
+    volume: u64,
+    market_cap: f64,
+    starred: bool,
+}
+
+impl Stock {
+    fn change_percent(&self) -> f64 {
+        self.change / (self.price - self.change) * 100.
+    }
+}
+
+/// Deterministic xorshift generator, so every launch shows the same data
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A float in `0.0..1.0`
+    fn unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.next() as usize % items.len()]
+    }
+}
+
+fn generate_stocks(count: usize) -> Vec<Stock> {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    (0..count)
+        .map(|id| {
+            let symbol = (0..3 + rng.next() % 2)
+                .map(|_| (b'A' + (rng.next() % 26) as u8) as char)
+                .collect::<String>();
+            let price = 5. + rng.unit() * 495.;
+            let change = (rng.unit() - 0.5) * price * 0.1;
+            let volume = 1_000 + rng.next() % 50_000_000;
+            Stock {
+                id,
+                symbol: symbol.into(),
+                name: format!("{} {}", rng.pick(&NAME_PREFIXES), rng.pick(&NAME_SUFFIXES)).into(),
+                sector: rng.pick(&SECTORS),
+                price,
+                change,
+                volume,
+                market_cap: price * (volume as f64) * (10. + rng.unit() * 90.),
+                starred: false,
+            }
+        })
+        .collect()
+}
+
+/// Format a large number with a K/M/B suffix
+fn abbreviate(value: f64) -> String {
+    match value {
+        v if v >= 1e9 => format!("{:.2}B", v / 1e9),
+        v if v >= 1e6 => format!("{:.2}M", v / 1e6),
+        v if v >= 1e3 => format!("{:.1}K", v / 1e3),
+        v => format!("{:.0}", v),
+    }
+}
+
+/// What the last frame of a table rendered, to show the virtualization at work
+#[derive(Clone, Copy, Default)]
+struct RenderStats {
+    rows: usize,
+    /// Time from the header to the last row, the rest of the frame is not included
+    row_render_time: Duration,
+}
+
+struct StockTableDelegate {
+    stocks: Vec<Stock>,
+    columns: Vec<Column>,
+    context_menu: bool,
+    render_start: Option<Instant>,
+    stats: RenderStats,
+}
+
+impl StockTableDelegate {
+    fn new(rows: usize) -> Self {
+        Self {
+            stocks: generate_stocks(rows),
+            columns: vec![
+                Column::new("id", "#").width(px(70.)).text_right(),
+                Column::new("symbol", "Symbol").width(px(90.)),
+                Column::new("name", "Name").width(px(180.)),
+                Column::new("sector", "Sector").width(px(120.)),
+                Column::new("price", "Price").width(px(100.)).text_right(),
+                Column::new("change", "Change").width(px(100.)).text_right(),
+                Column::new("change_percent", "Change %")
+                    .width(px(100.))
+                    .text_right(),
+                Column::new("volume", "Volume").width(px(110.)).text_right(),
+                Column::new("market_cap", "Market Cap")
+                    .width(px(120.))
+                    .text_right(),
+            ],
+            context_menu: false,
+            render_start: None,
+            stats: RenderStats::default(),
+        }
+    }
+
+    /// Make every column except the row number sortable
+    fn sortable(mut self) -> Self {
+        self.columns = self
+            .columns
+            .into_iter()
+            .map(|column| {
+                if column.key == "id" {
+                    column
+                } else {
+                    column.sortable()
+                }
+            })
+            .collect();
+        self
+    }
+
+    /// Keep the row number and symbol visible while scrolling horizontally
+    fn fixed_left(mut self) -> Self {
+        for column in self.columns.iter_mut().take(2) {
+            *column = column.clone().fixed_left();
+        }
+        self
+    }
+
+    fn context_menu(mut self) -> Self {
+        self.context_menu = true;
+        self
+    }
+
+    fn stock(&self, row_ix: usize) -> Option<&Stock> {
+        self.stocks.get(row_ix)
+    }
+}
+
+impl TableDelegate for StockTableDelegate {
+    fn columns_count(&self, _cx: &App) -> usize {
+        self.columns.len()
+    }
+
+    fn rows_count(&self, _cx: &App) -> usize {
+        self.stocks.len()
+    }
+
+    fn column(&self, col_ix: usize, _cx: &App) -> &Column {
+        &self.columns[col_ix]
+    }
+
+    fn perform_sort(
+        &mut self,
+        col_ix: usize,
+        sort: ColumnSort,
+        _window: &mut Window,
+        _cx: &mut Context<TableState<Self>>,
+    ) {
+        if sort == ColumnSort::Default {
+            // Back to the generated order
+            self.stocks.sort_by_key(|stock| stock.id);
+            return;
+        }
+
+        let key = self.columns[col_ix].key.clone();
+        let by_float =
+            |value: fn(&Stock) -> f64| move |a: &Stock, b: &Stock| value(a).total_cmp(&value(b));
+
+        match key.as_ref() {
+            "symbol" => self.stocks.sort_by(|a, b| a.symbol.cmp(&b.symbol)),
+            "name" => self.stocks.sort_by(|a, b| a.name.cmp(&b.name)),
+            "sector" => self.stocks.sort_by_key(|stock| stock.sector),
+            "price" => self.stocks.sort_by(by_float(|stock| stock.price)),
+            "change" => self.stocks.sort_by(by_float(|stock| stock.change)),
+            "change_percent" => self.stocks.sort_by(by_float(Stock::change_percent)),
+            "volume" => self.stocks.sort_by_key(|stock| stock.volume),
+            "market_cap" => self.stocks.sort_by(by_float(|stock| stock.market_cap)),
+            _ => self.stocks.sort_by_key(|stock| stock.id),
+        }
+
+        if sort == ColumnSort::Descending {
+            self.stocks.reverse();
+        }
+    }
+
+    fn move_column(
+        &mut self,
+        col_ix: usize,
+        to_ix: usize,
+        _window: &mut Window,
+        _cx: &mut Context<TableState<Self>>,
+    ) {
+        let column = self.columns.remove(col_ix);
+        self.columns.insert(to_ix, column);
+    }
+
+    fn render_header(
+        &mut self,
+        _window: &mut Window,
+        _cx: &mut Context<TableState<Self>>,
+    ) -> Stateful<Div> {
+        // The header is rendered first in every frame, the rows follow
+        self.render_start = Some(Instant::now());
+        self.stats.rows = 0;
+        div().id("header")
+    }
+
+    fn render_tr(
+        &mut self,
+        row_ix: usize,
+        _window: &mut Window,
+        _cx: &mut Context<TableState<Self>>,
+    ) -> Stateful<Div> {
+        // Rows past the end only fill the remaining space
+        if row_ix < self.stocks.len() {
+            self.stats.rows += 1;
+            if let Some(start) = self.render_start {
+                self.stats.row_render_time = start.elapsed();
+            }
+        }
+        div().id(("row", row_ix))
+    }
+
+    fn visible_rows_changed(
+        &mut self,
+        _visible_range: Range<usize>,
+        window: &mut Window,
+        cx: &mut Context<TableState<Self>>,
+    ) {
+        // The stats are complete once this frame is drawn, redraw to show them
+        cx.on_next_frame(window, |_, _, cx| cx.notify());
+    }
+
+    fn context_menu(
+        &mut self,
+        row_ix: usize,
+        menu: PopupMenu,
+        _window: &mut Window,
+        cx: &mut Context<TableState<Self>>,
+    ) -> PopupMenu {
+        let Some(stock) = self.stock(row_ix).filter(|_| self.context_menu) else {
+            return menu;
+        };
+
+        let symbol = stock.symbol.clone();
+        let starred = stock.starred;
+        let toggle_state = cx.entity().downgrade();
+        let delete_state = toggle_state.clone();
+
+        menu.item(
+            PopupMenuItem::new(format!("Copy \"{}\"", symbol))
+                .icon(IconName::Copy)
+                .on_click(move |_, _, cx| {
+                    cx.write_to_clipboard(ClipboardItem::new_string(symbol.to_string()));
+                }),
+        )
+        .item(
+            PopupMenuItem::new(if starred { "Unstar" } else { "Star" })
+                .icon(IconName::Star)
+                .on_click(move |_, _, cx| {
+                    toggle_state
+                        .update(cx, |state, cx| {
+                            if let Some(stock) = state.delegate_mut().stocks.get_mut(row_ix) {
+                                stock.starred = !stock.starred;
+                            }
+                            cx.notify();
+                        })
+                        .ok();
+                }),
+        )
+        .separator()
+        .item(
+            PopupMenuItem::new("Delete row")
+                .icon(IconName::Delete)
+                .on_click(move |_, _, cx| {
+                    delete_state
+                        .update(cx, |state, cx| {
+                            let stocks = &mut state.delegate_mut().stocks;
+                            if row_ix < stocks.len() {
+                                stocks.remove(row_ix);
+                            }
+                            state.refresh(cx);
+                        })
+                        .ok();
+                }),
+        )
+    }
+
+    fn render_td(
+        &mut self,
+        row_ix: usize,
+        col_ix: usize,
+        _window: &mut Window,
+        cx: &mut Context<TableState<Self>>,
+    ) -> impl IntoElement {
+        let Some(stock) = self.stocks.get(row_ix) else {
+            return div().into_any_element();
+        };
+
+        let change_color = if stock.change >= 0. {
+            cx.theme().green
+        } else {
+            cx.theme().red
+        };
+
+        match self.columns[col_ix].key.as_ref() {
+            "id" => stock.id.to_string().into_any_element(),
+            "symbol" => h_flex()
+                .gap_1()
+                .font_weight(FontWeight::SEMIBOLD)
+                .child(stock.symbol.clone())
+                .when(stock.starred, |this| {
+                    this.child(
+                        Icon::new(IconName::Star)
+                            .xsmall()
+                            .text_color(cx.theme().yellow),
+                    )
+                })
+                .into_any_element(),
+            "name" => stock.name.clone().into_any_element(),
+            "sector" => stock.sector.into_any_element(),
+            "price" => format!("{:.2}", stock.price).into_any_element(),
+            "change" => div()
+                .text_color(change_color)
+                .child(format!("{:+.2}", stock.change))
+                .into_any_element(),
+            "change_percent" => div()
+                .text_color(change_color)
+                .child(format!("{:+.2}%", stock.change_percent()))
+                .into_any_element(),
+            "volume" => abbreviate(stock.volume as f64).into_any_element(),
+            "market_cap" => abbreviate(stock.market_cap).into_any_element(),
+            _ => div().into_any_element(),
+        }
+    }
+}
+
+pub struct TableComponentView {
+    basic: Entity<TableState<StockTableDelegate>>,
+    sortable: Entity<TableState<StockTableDelegate>>,
+    resizable: Entity<TableState<StockTableDelegate>>,
+    fixed_columns: Entity<TableState<StockTableDelegate>>,
+    row_selection: Entity<TableState<StockTableDelegate>>,
+    context_menu: Entity<TableState<StockTableDelegate>>,
+    large: Entity<TableState<StockTableDelegate>>,
+}
+
+impl ComponentMeta for TableComponentView {
+    const NAME: &'static str = "Table";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataDisplay;
+    const DESCRIPTION: &'static str = "A high-performance data table that renders only the visible rows and columns. \nColumns can be sorted, resized, reordered and fixed to the left, \nand rows support selection, keyboard navigation and context menus.";
+    const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/table";
+
+    fn create(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        // Each example gets a table with only the features it shows
+        let mut table = |delegate: StockTableDelegate,
+                         configure: fn(
+            TableState<StockTableDelegate>,
+        ) -> TableState<StockTableDelegate>| {
+            cx.new(|cx| {
+                configure(
+                    TableState::new(delegate, window, cx)
+                        .sortable(false)
+                        .col_movable(false)
+                        .col_resizable(false)
+                        .row_selectable(false)
+                        .col_selectable(false),
+                )
+            })
+        };
+
+        Self {
+            basic: table(StockTableDelegate::new(20), |state| state),
+            sortable: table(StockTableDelegate::new(1_000).sortable(), |state| {
+                state.sortable(true)
+            }),
+            resizable: table(StockTableDelegate::new(1_000), |state| {
+                state.col_resizable(true).col_movable(true)
+            }),
+            fixed_columns: table(StockTableDelegate::new(1_000).fixed_left(), |state| {
+                state.col_resizable(true)
+            }),
+            row_selection: table(StockTableDelegate::new(1_000), |state| {
+                state.row_selectable(true)
+            }),
+            context_menu: table(StockTableDelegate::new(1_000).context_menu(), |state| {
+                state.row_selectable(true)
+            }),
+            large: table(
+                StockTableDelegate::new(LARGE_ROWS)
+                    .sortable()
+                    .fixed_left()
+                    .context_menu(),
+                |state| {
+                    state
+                        .sortable(true)
+                        .col_resizable(true)
+                        .col_movable(true)
+                        .row_selectable(true)
+                },
+            ),
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_table", "Basic Table")
+                .full_width()
+                .render(|this, _, _| this.basic_table()),
+            Self::example("sortable_columns", "Sortable Columns")
+                .description("Click a column header to sort ascending, descending, then back to the original order.")
+                .full_width()
+                .render(|this, _, _| this.sortable_columns()),
+            Self::example("resizable_and_movable_columns", "Resizable and Movable Columns")
+                .description("Drag the edge of a header to resize a column, drag the header to reorder it.")
+                .full_width()
+                .render(|this, _, _| this.resizable_and_movable_columns()),
+            Self::example("fixed_columns", "Fixed Columns")
+                .description("The # and Symbol columns stay in place while scrolling horizontally.")
+                .full_width()
+                .render(|this, _, _| this.fixed_columns()),
+            Self::example("row_selection", "Row Selection")
+                .description("Click a row or use the arrow keys to move the selection.")
+                .full_width()
+                .render(|this, _, cx| this.row_selection(cx)),
+            Self::example("context_menu", "Context Menu")
+                .description("Right click a row to copy its symbol, star it or delete it.")
+                .full_width()
+                .render(|this, _, _| this.context_menu()),
+            Self::example("virtualized_rows", "100,000 Rows")
+                .description("Only the visible rows are rendered, whatever the size of the data set. The row render time covers building the header and the visible rows, not the layout and painting of the whole frame.")
+                .full_width()
+                .render(|this, _, cx| this.virtualized_rows(cx)),
+        ]
+    }
+}
+
+impl TableComponentView {
+    // Example code for the Table component
+
+    fn basic_table(&self) -> AnyElement {
+        div()
+            .h(px(320.))
+            .child(Table::new(&self.basic).stripe(true))
+            .into_any_element()
+    }
+
+    fn sortable_columns(&self) -> AnyElement {
+        div()
+            .h(px(320.))
+            .child(Table::new(&self.sortable))
+            .into_any_element()
+    }
+
+    fn resizable_and_movable_columns(&self) -> AnyElement {
+        div()
+            .h(px(320.))
+            .child(Table::new(&self.resizable))
+            .into_any_element()
+    }
+
+    fn fixed_columns(&self) -> AnyElement {
+        // Narrow enough to scroll horizontally
+        div()
+            .h(px(320.))
+            .max_w(px(560.))
+            .child(Table::new(&self.fixed_columns))
+            .into_any_element()
+    }
+
+    fn row_selection(&self, cx: &mut Context<Self>) -> AnyElement {
+        let state = self.row_selection.read(cx);
+        let selected = state
+            .selected_row()
+            .and_then(|row_ix| state.delegate().stock(row_ix))
+            .map(|stock| format!("Selected: {} ({})", stock.symbol, stock.name))
+            .unwrap_or_else(|| "No row selected".to_string());
+
+        v_flex()
+            .gap_2()
+            .child(
+                div()
+                    .h(px(320.))
+                    .child(Table::new(&self.row_selection).stripe(true)),
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(selected),
+            )
+            .into_any_element()
+    }
+
+    fn context_menu(&self) -> AnyElement {
+        div()
+            .h(px(320.))
+            .child(Table::new(&self.context_menu))
+            .into_any_element()
+    }
+
+    fn virtualized_rows(&self, cx: &mut Context<Self>) -> AnyElement {
+        let state = self.large.read(cx);
+        let delegate = state.delegate();
+        let stats = delegate.stats;
+        let selected = state
+            .selected_row()
+            .and_then(|row_ix| delegate.stock(row_ix))
+            .map(|stock| stock.symbol.to_string())
+            .unwrap_or_else(|| "none".to_string());
+
+        v_flex()
+            .gap_2()
+            .child(
+                div()
+                    .h(px(400.))
+                    .child(Table::new(&self.large).stripe(true)),
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format!(
+                        "Rows rendered: {} of {} · Row render time: {:.2} ms · Selected: {}",
+                        stats.rows,
+                        delegate.stocks.len(),
+                        stats.row_render_time.as_secs_f64() * 1000.,
+                        selected
+                    )),
+            )
+            .into_any_element()
+    }
+}
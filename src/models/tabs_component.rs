@@ -0,0 +1,474 @@
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::badge::Badge;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::input::{Input, InputState};
+use gpui_component::tab::{Tab, TabBar, TabVariant};
+use gpui_component::*;
+
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+
+const VARIANTS: [(TabVariant, &str); 5] = [
+    (TabVariant::Tab, "Tab"),
+    (TabVariant::Outline, "Outline"),
+    (TabVariant::Pill, "Pill"),
+    (TabVariant::Segmented, "Segmented"),
+    (TabVariant::Underline, "Underline"),
+];
+
+const OVERFLOW_TABS: usize = 50;
+
+/// Content of a tab, a view of its own so its state outlives switching tabs
+struct TabContent {
+    title: SharedString,
+    clicks: usize,
+    notes: Entity<InputState>,
+}
+
+impl TabContent {
+    fn new(title: impl Into<SharedString>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let title = title.into();
+        let notes =
+            cx.new(|cx| InputState::new(window, cx).placeholder(format!("Notes for {}…", title)));
+        Self {
+            title,
+            clicks: 0,
+            notes,
+        }
+    }
+}
+
+impl Render for TabContent {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_3()
+            .p_4()
+            .child(
+                div()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .child(self.title.clone()),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("tab-content-click")
+                            .label("Click me")
+                            .small()
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.clicks += 1;
+                                cx.notify();
+                            })),
+                    )
+                    .child(format!("Clicked {} times", self.clicks)),
+            )
+            .child(Input::new(&self.notes))
+    }
+}
+
+/// A tab being dragged to a new position
+#[derive(Clone)]
+struct DraggedTab {
+    /// Id of the tab bar, tabs only move within their own bar
+    bar: &'static str,
+    ix: usize,
+    label: SharedString,
+}
+
+impl Render for DraggedTab {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .px_3()
+            .py_1()
+            .rounded_md()
+            .border_1()
+            .border_color(cx.theme().primary)
+            .bg(cx.theme().background)
+            .text_sm()
+            .child(self.label.clone())
+    }
+}
+
+/// Move the item at `from` to `to`, the selected item stays selected
+fn move_item<T>(items: &mut Vec<T>, from: usize, to: usize, selected: &mut usize) {
+    if from == to || from >= items.len() || to >= items.len() {
+        return;
+    }
+
+    let item = items.remove(from);
+    items.insert(to, item);
+    *selected = if *selected == from {
+        to
+    } else if from < *selected && *selected <= to {
+        *selected - 1
+    } else if to <= *selected && *selected < from {
+        *selected + 1
+    } else {
+        *selected
+    };
+}
+
+/// Open document of the closable tabs example
+struct Document {
+    title: SharedString,
+    content: Entity<TabContent>,
+}
+
+pub struct TabsComponentView {
+    variant_selected: [usize; VARIANTS.len()],
+    slots_selected: usize,
+    documents: Vec<Document>,
+    active_document: usize,
+    /// Number used to name the next new document
+    next_document: usize,
+    reorder_tabs: Vec<SharedString>,
+    reorder_selected: usize,
+    overflow_selected: usize,
+    overflow_scroll: ScrollHandle,
+    panels: Vec<Entity<TabContent>>,
+    panel_selected: usize,
+}
+
+impl ComponentMeta for TabsComponentView {
+    const NAME: &'static str = "Tabs";
+    const CATEGORY: ComponentCategory = ComponentCategory::Layout;
+    const DESCRIPTION: &'static str = "A set of layered sections of content, known as tab panels, displayed one at a time. \nThe TabBar comes in several variants, supports prefix and suffix slots, \nand can scroll or list its tabs in a menu when they do not fit.";
+    const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/tabs";
+
+    fn create(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let documents = (1..=3)
+            .map(|ix| {
+                let title: SharedString = format!("Untitled-{}", ix).into();
+                Document {
+                    content: cx.new(|cx| TabContent::new(title.clone(), window, cx)),
+                    title,
+                }
+            })
+            .collect();
+        let panels = ["Account", "Password", "Notifications"]
+            .into_iter()
+            .map(|title| cx.new(|cx| TabContent::new(title, window, cx)))
+            .collect();
+
+        Self {
+            variant_selected: [0; VARIANTS.len()],
+            slots_selected: 0,
+            documents,
+            active_document: 0,
+            next_document: 4,
+            reorder_tabs: ["Overview", "Activity", "Settings", "Members", "Billing"]
+                .into_iter()
+                .map(SharedString::from)
+                .collect(),
+            reorder_selected: 0,
+            overflow_selected: 0,
+            overflow_scroll: ScrollHandle::new(),
+            panels,
+            panel_selected: 0,
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("tab_variants", "Tab Variants")
+                .full_width()
+                .render(|this, _, cx| this.tab_variants(cx)),
+            Self::example("prefix_and_suffix", "Prefix and Suffix")
+                .full_width()
+                .render(|this, _, cx| this.prefix_and_suffix(cx)),
+            Self::example("closable_tabs", "Closable Tabs")
+                .description("Close tabs with their x button, open new ones with +, drag a tab to move it. Each tab keeps its content while another one is shown.")
+                .full_width()
+                .render(|this, window, cx| this.closable_tabs(window, cx)),
+            Self::example("drag_to_reorder", "Drag to Reorder")
+                .full_width()
+                .render(|this, _, cx| this.drag_to_reorder(cx)),
+            Self::example("overflowing_tabs", "Overflowing Tabs")
+                .description("Scroll the bar horizontally, or pick a tab from the menu on the right.")
+                .full_width()
+                .render(|this, _, cx| this.overflowing_tabs(cx)),
+            Self::example("tab_panel", "Tab Panel")
+                .description("Click the button or type in a panel, switch tabs and come back: the panel is as you left it.")
+                .full_width()
+                .render(|this, _, cx| this.tab_panel(cx)),
+        ]
+    }
+}
+
+impl TabsComponentView {
+    /// Make `tab` draggable within the bar `bar`, dropping it moves it with `on_move`
+    fn draggable_tab(
+        tab: Tab,
+        bar: &'static str,
+        ix: usize,
+        label: SharedString,
+        cx: &Context<Self>,
+        on_move: fn(&mut Self, usize, usize),
+    ) -> Tab {
+        tab.on_drag(DraggedTab { bar, ix, label }, |drag, _, _, cx| {
+            cx.stop_propagation();
+            cx.new(|_| drag.clone())
+        })
+        .drag_over::<DraggedTab>(move |style, drag, _, cx| {
+            if drag.bar == bar {
+                style.bg(cx.theme().drop_target)
+            } else {
+                style
+            }
+        })
+        .on_drop(cx.listener(move |this, drag: &DraggedTab, _, cx| {
+            if drag.bar == bar {
+                on_move(this, drag.ix, ix);
+                cx.notify();
+            }
+        }))
+    }
+
+    fn new_document(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let title: SharedString = format!("Untitled-{}", self.next_document).into();
+        self.next_document += 1;
+        self.documents.push(Document {
+            content: cx.new(|cx| TabContent::new(title.clone(), window, cx)),
+            title,
+        });
+        self.active_document = self.documents.len() - 1;
+        cx.notify();
+    }
+
+    fn close_document(&mut self, ix: usize, cx: &mut Context<Self>) {
+        if ix >= self.documents.len() {
+            return;
+        }
+
+        self.documents.remove(ix);
+        if ix < self.active_document {
+            self.active_document -= 1;
+        }
+        self.active_document = self
+            .active_document
+            .min(self.documents.len().saturating_sub(1));
+        cx.notify();
+    }
+
+    // Example code for the Tabs component
+
+    fn tab_variants(&self, cx: &mut Context<Self>) -> AnyElement {
+        v_flex()
+            .gap_4()
+            .children(VARIANTS.iter().enumerate().map(|(ix, (variant, name))| {
+                v_flex()
+                    .gap_1()
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(*name),
+                    )
+                    .child(
+                        TabBar::new(("tab-variant", ix))
+                            .with_variant(*variant)
+                            .selected_index(self.variant_selected[ix])
+                            .on_click(cx.listener(move |this, selected: &usize, _, cx| {
+                                this.variant_selected[ix] = *selected;
+                                cx.notify();
+                            }))
+                            .child(Tab::new().label("Account"))
+                            .child(Tab::new().label("Profile"))
+                            .child(Tab::new().label("Settings"))
+                            .child(Tab::new().label("Disabled").disabled(true)),
+                    )
+            }))
+            .into_any_element()
+    }
+
+    fn prefix_and_suffix(&self, cx: &mut Context<Self>) -> AnyElement {
+        TabBar::new("tab-slots")
+            .underline()
+            .selected_index(self.slots_selected)
+            .on_click(cx.listener(|this, selected: &usize, _, cx| {
+                this.slots_selected = *selected;
+                cx.notify();
+            }))
+            .prefix(
+                Button::new("tab-slots-back")
+                    .icon(IconName::ChevronLeft)
+                    .ghost()
+                    .xsmall(),
+            )
+            .suffix(
+                Button::new("tab-slots-settings")
+                    .icon(IconName::Settings)
+                    .ghost()
+                    .xsmall(),
+            )
+            .child(
+                Tab::new()
+                    .label("Inbox")
+                    .prefix(Icon::new(IconName::Inbox).small())
+                    .suffix(Badge::new().count(12).child(div().w_3())),
+            )
+            .child(
+                Tab::new()
+                    .label("Starred")
+                    .prefix(Icon::new(IconName::Star).small()),
+            )
+            .child(
+                Tab::new()
+                    .label("Alerts")
+                    .prefix(Icon::new(IconName::Bell).small())
+                    .suffix(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child("new"),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    fn closable_tabs(&self, _window: &mut Window, cx: &mut Context<Self>) -> AnyElement {
+        let tabs = self.documents.iter().enumerate().map(|(ix, document)| {
+            let tab = Tab::new().label(document.title.clone()).suffix(
+                Button::new(("close-document", ix))
+                    .icon(IconName::Close)
+                    .ghost()
+                    .xsmall()
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        // Don't let the tab select itself once closed
+                        cx.stop_propagation();
+                        this.close_document(ix, cx);
+                    })),
+            );
+            Self::draggable_tab(
+                tab,
+                "documents",
+                ix,
+                document.title.clone(),
+                cx,
+                |this, from, to| {
+                    move_item(&mut this.documents, from, to, &mut this.active_document)
+                },
+            )
+        });
+
+        v_flex()
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded_md()
+            .child(
+                TabBar::new("tab-documents")
+                    .selected_index(self.active_document)
+                    .on_click(cx.listener(|this, selected: &usize, _, cx| {
+                        this.active_document = *selected;
+                        cx.notify();
+                    }))
+                    .children(tabs)
+                    .suffix(
+                        Button::new("new-document")
+                            .icon(IconName::Plus)
+                            .ghost()
+                            .xsmall()
+                            .mx_1()
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.new_document(window, cx);
+                            })),
+                    ),
+            )
+            .child(div().min_h(px(180.)).map(|this| {
+                match self.documents.get(self.active_document) {
+                    Some(document) => this.child(document.content.clone()),
+                    None => this.child(
+                        v_flex()
+                            .size_full()
+                            .min_h(px(180.))
+                            .items_center()
+                            .justify_center()
+                            .text_color(cx.theme().muted_foreground)
+                            .child("No open tabs, press + to open one"),
+                    ),
+                }
+            }))
+            .into_any_element()
+    }
+
+    fn drag_to_reorder(&self, cx: &mut Context<Self>) -> AnyElement {
+        let tabs = self.reorder_tabs.iter().enumerate().map(|(ix, label)| {
+            Self::draggable_tab(
+                Tab::new().label(label.clone()),
+                "reorder",
+                ix,
+                label.clone(),
+                cx,
+                |this, from, to| {
+                    move_item(&mut this.reorder_tabs, from, to, &mut this.reorder_selected)
+                },
+            )
+        });
+
+        v_flex()
+            .gap_2()
+            .child(
+                TabBar::new("tab-reorder")
+                    .pill()
+                    .selected_index(self.reorder_selected)
+                    .on_click(cx.listener(|this, selected: &usize, _, cx| {
+                        this.reorder_selected = *selected;
+                        cx.notify();
+                    }))
+                    .children(tabs),
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format!(
+                        "Order: {}",
+                        self.reorder_tabs
+                            .iter()
+                            .map(|label| label.as_ref())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )),
+            )
+            .into_any_element()
+    }
+
+    fn overflowing_tabs(&self, cx: &mut Context<Self>) -> AnyElement {
+        TabBar::new("tab-overflow")
+            .track_scroll(&self.overflow_scroll)
+            .menu(true)
+            .selected_index(self.overflow_selected)
+            .on_click(cx.listener(|this, selected: &usize, _, cx| {
+                this.overflow_selected = *selected;
+                // Bring a tab picked from the menu into view
+                this.overflow_scroll.scroll_to_item(*selected);
+                cx.notify();
+            }))
+            .children((1..=OVERFLOW_TABS).map(|ix| Tab::new().label(format!("Tab {}", ix))))
+            .into_any_element()
+    }
+
+    fn tab_panel(&self, cx: &mut Context<Self>) -> AnyElement {
+        let tabs = self
+            .panels
+            .iter()
+            .map(|panel| Tab::new().label(panel.read(cx).title.clone()))
+            .collect::<Vec<_>>();
+
+        v_flex()
+            .gap_2()
+            .child(
+                TabBar::new("tab-panel")
+                    .segmented()
+                    .selected_index(self.panel_selected)
+                    .on_click(cx.listener(|this, selected: &usize, _, cx| {
+                        this.panel_selected = *selected;
+                        cx.notify();
+                    }))
+                    .children(tabs),
+            )
+            // Only the selected panel is rendered, the others stay alive as entities
+            .child(self.panels[self.panel_selected].clone())
+            .into_any_element()
+    }
+}
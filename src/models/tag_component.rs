@@ -0,0 +1,191 @@
+use gpui::*;
+use gpui_component::input::{Input, InputEvent, InputState};
+use gpui_component::tag::Tag;
+use gpui_component::*;
+
+use crate::models::{ComponentCategory, ComponentMeta, Example, value_label};
+
+const COLORS: [ColorName; 6] = [
+    ColorName::Red,
+    ColorName::Orange,
+    ColorName::Green,
+    ColorName::Blue,
+    ColorName::Purple,
+    ColorName::Gray,
+];
+
+pub struct TagComponentView {
+    labels: Vec<SharedString>,
+    keywords: Vec<SharedString>,
+    keyword_input: Entity<InputState>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ComponentMeta for TagComponentView {
+    const NAME: &'static str = "Tag";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataDisplay;
+    const DESCRIPTION: &'static str = "A Tag is a small label for a status, a category or a keyword. \nSupports semantic variants, palette colors, outline and rounded styles, \nand takes any children, such as a close button.";
+    const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/tag";
+
+    fn create(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let keyword_input =
+            cx.new(|cx| InputState::new(window, cx).placeholder("Type a keyword and press Enter"));
+        let _subscriptions =
+            vec![cx.subscribe_in(&keyword_input, window, Self::on_keyword_input_event)];
+
+        Self {
+            labels: ["bug", "help wanted", "good first issue", "documentation"]
+                .map(SharedString::from)
+                .into(),
+            keywords: vec!["rust".into(), "gpui".into()],
+            keyword_input,
+            _subscriptions,
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("variants", "Variants").render(|this, _, _| this.variants()),
+            Self::example("colors", "Colors").render(|this, _, _| this.colors()),
+            Self::example("outline_and_rounded", "Outline and Rounded")
+                .render(|this, _, _| this.outline_and_rounded()),
+            Self::example("closable_tags", "Closable Tags")
+                .render(|this, _, cx| this.closable_tags(cx)),
+            Self::example("tag_input", "Tag Input").render(|this, _, cx| this.tag_input(cx)),
+        ]
+    }
+}
+
+impl TagComponentView {
+    // Example code for the Tag component
+
+    fn variants(&self) -> AnyElement {
+        h_flex()
+            .gap_2()
+            .flex_wrap()
+            .child(Tag::primary().child("Primary"))
+            .child(Tag::secondary().child("Secondary"))
+            .child(Tag::success().child("Success"))
+            .child(Tag::warning().child("Warning"))
+            .child(Tag::danger().child("Danger"))
+            .child(Tag::info().child("Info"))
+            .into_any_element()
+    }
+
+    fn colors(&self) -> AnyElement {
+        h_flex()
+            .gap_2()
+            .flex_wrap()
+            .children(COLORS.map(|color| Tag::color(color).child(color.to_string())))
+            .into_any_element()
+    }
+
+    fn outline_and_rounded(&self) -> AnyElement {
+        v_flex()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(Tag::primary().outline().child("Outline"))
+                    .child(Tag::success().outline().child("Outline"))
+                    .child(Tag::danger().outline().child("Outline")),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .child(Tag::info().rounded_full().small().child("Small"))
+                    .child(Tag::info().rounded_full().child("Rounded"))
+                    .child(Tag::warning().rounded(px(2.)).child("Square")),
+            )
+            .into_any_element()
+    }
+
+    fn closable_tags(&self, cx: &mut Context<Self>) -> AnyElement {
+        v_flex()
+            .gap_3()
+            .child(removable_tags(
+                "label",
+                &self.labels,
+                |this| &mut this.labels,
+                cx,
+            ))
+            .child(value_label(
+                if self.labels.is_empty() {
+                    "Labels: none".to_string()
+                } else {
+                    format!("Labels: {}", self.labels.join(", "))
+                },
+                cx,
+            ))
+            .into_any_element()
+    }
+
+    fn tag_input(&self, cx: &mut Context<Self>) -> AnyElement {
+        v_flex()
+            .gap_3()
+            .max_w(px(420.))
+            .child(Input::new(&self.keyword_input))
+            .child(removable_tags(
+                "keyword",
+                &self.keywords,
+                |this| &mut this.keywords,
+                cx,
+            ))
+            .child(value_label(
+                format!("Keywords: {}", self.keywords.join(", ")),
+                cx,
+            ))
+            .into_any_element()
+    }
+
+    fn on_keyword_input_event(
+        &mut self,
+        input: &Entity<InputState>,
+        event: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::PressEnter { .. } = event {
+            let keyword: SharedString = input.read(cx).value().trim().to_string().into();
+            if keyword.is_empty() {
+                return;
+            }
+            // A keyword already in the list is only cleared from the input
+            if !self.keywords.contains(&keyword) {
+                self.keywords.push(keyword);
+            }
+            input.update(cx, |input, cx| input.set_value("", window, cx));
+            cx.notify();
+        }
+    }
+}
+
+/// Tags with a close button, removing the tag from the list picked by `list`
+fn removable_tags(
+    id: &'static str,
+    tags: &[SharedString],
+    list: fn(&mut TagComponentView) -> &mut Vec<SharedString>,
+    cx: &mut Context<TagComponentView>,
+) -> Div {
+    h_flex()
+        .gap_2()
+        .flex_wrap()
+        .children(tags.iter().enumerate().map(|(ix, tag)| {
+            // Removed by value, the index may be stale after a click not
+            // yet rendered
+            let removed = tag.clone();
+            Tag::secondary().gap_1().child(tag.clone()).child(
+                div()
+                    .id((id, ix))
+                    .cursor_pointer()
+                    .text_color(cx.theme().muted_foreground)
+                    .hover(|this| this.text_color(cx.theme().foreground))
+                    .child(Icon::new(IconName::Close).xsmall())
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        list(this).retain(|tag| tag != &removed);
+                        cx.notify();
+                    })),
+            )
+        }))
+}
@@ -0,0 +1,199 @@
+use gpui::*;
+use gpui_component::button::{Toggle, ToggleGroup, ToggleVariants};
+use gpui_component::*;
+
+use crate::models::{ComponentCategory, ComponentMeta, Example, value_label};
+
+const FILTERS: [(&str, IconName); 3] = [
+    ("Starred", IconName::Star),
+    ("Liked", IconName::ThumbsUp),
+    ("Inbox", IconName::Inbox),
+];
+
+const THEMES: [(&str, IconName); 2] = [("Light", IconName::Sun), ("Dark", IconName::Moon)];
+
+pub struct ToggleComponentView {
+    favorite: bool,
+    visible: bool,
+    filters: [bool; 3],
+    theme: usize,
+}
+
+impl ComponentMeta for ToggleComponentView {
+    const NAME: &'static str = "Toggle";
+    const CATEGORY: ComponentCategory = ComponentCategory::General;
+    const DESCRIPTION: &'static str = "A Toggle is a button that stays pressed until clicked again. \nA ToggleGroup reports the state of all of its toggles on every click, \nso it can act as a multiple or a single choice.";
+    const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/toggle";
+
+    fn create(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
+        Self {
+            favorite: false,
+            visible: true,
+            filters: [true, false, false],
+            theme: 0,
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_toggle", "Basic Toggle")
+                .render(|this, _, cx| this.basic_toggle(cx)),
+            Self::example("multiple_choice", "Toggle Group, Multiple Choice")
+                .render(|this, _, cx| this.multiple_choice(cx)),
+            Self::example("single_choice", "Toggle Group, Single Choice")
+                .render(|this, _, cx| this.single_choice(cx)),
+            Self::example("variants_and_sizes", "Variants and Sizes")
+                .render(|this, _, _| this.variants_and_sizes()),
+        ]
+    }
+}
+
+impl ToggleComponentView {
+    // Example code for the Toggle component
+
+    fn basic_toggle(&self, cx: &mut Context<Self>) -> AnyElement {
+        v_flex()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Toggle::new("favorite")
+                            .icon(if self.favorite {
+                                IconName::Heart
+                            } else {
+                                IconName::HeartOff
+                            })
+                            .checked(self.favorite)
+                            .on_click(cx.listener(|this, checked: &bool, _, cx| {
+                                this.favorite = *checked;
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Toggle::new("visible")
+                            .outline()
+                            .icon(if self.visible {
+                                IconName::Eye
+                            } else {
+                                IconName::EyeOff
+                            })
+                            .label("Visible")
+                            .checked(self.visible)
+                            .on_click(cx.listener(|this, checked: &bool, _, cx| {
+                                this.visible = *checked;
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .child(value_label(
+                format!("Favorite: {} · Visible: {}", self.favorite, self.visible),
+                cx,
+            ))
+            .into_any_element()
+    }
+
+    fn multiple_choice(&self, cx: &mut Context<Self>) -> AnyElement {
+        let active = FILTERS
+            .iter()
+            .zip(self.filters)
+            .filter(|(_, checked)| *checked)
+            .map(|((label, _), _)| *label)
+            .collect::<Vec<_>>();
+
+        v_flex()
+            .gap_3()
+            .child(
+                ToggleGroup::new("filters")
+                    .outline()
+                    .children(FILTERS.into_iter().enumerate().map(|(ix, (label, icon))| {
+                        Toggle::new(label)
+                            .icon(icon)
+                            .label(label)
+                            .checked(self.filters[ix])
+                    }))
+                    .on_click(cx.listener(|this, checks: &Vec<bool>, _, cx| {
+                        for (filter, checked) in this.filters.iter_mut().zip(checks) {
+                            *filter = *checked;
+                        }
+                        cx.notify();
+                    })),
+            )
+            .child(value_label(
+                if active.is_empty() {
+                    "Filters: none".to_string()
+                } else {
+                    format!("Filters: {}", active.join(", "))
+                },
+                cx,
+            ))
+            .into_any_element()
+    }
+
+    fn single_choice(&self, cx: &mut Context<Self>) -> AnyElement {
+        v_flex()
+            .gap_3()
+            .child(
+                ToggleGroup::new("themes")
+                    .outline()
+                    .children(THEMES.into_iter().enumerate().map(|(ix, (label, icon))| {
+                        Toggle::new(label)
+                            .icon(icon)
+                            .label(label)
+                            .checked(self.theme == ix)
+                    }))
+                    .on_click(cx.listener(|this, checks: &Vec<bool>, _, cx| {
+                        // Only the newly pressed toggle stays checked, clicking
+                        // the checked one again keeps it
+                        if let Some(ix) = checks
+                            .iter()
+                            .enumerate()
+                            .find(|(ix, checked)| **checked && *ix != this.theme)
+                            .map(|(ix, _)| ix)
+                        {
+                            this.theme = ix;
+                            cx.notify();
+                        }
+                    })),
+            )
+            .child(value_label(format!("Theme: {}", THEMES[self.theme].0), cx))
+            .into_any_element()
+    }
+
+    fn variants_and_sizes(&self) -> AnyElement {
+        v_flex()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(Toggle::new("ghost").label("Ghost").checked(true))
+                    .child(
+                        Toggle::new("outline")
+                            .outline()
+                            .label("Outline")
+                            .checked(true),
+                    )
+                    .child(
+                        Toggle::new("disabled")
+                            .outline()
+                            .label("Disabled")
+                            .disabled(true),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .child(
+                        Toggle::new("xsmall")
+                            .outline()
+                            .xsmall()
+                            .icon(IconName::Star),
+                    )
+                    .child(Toggle::new("small").outline().small().icon(IconName::Star))
+                    .child(Toggle::new("medium").outline().icon(IconName::Star))
+                    .child(Toggle::new("large").outline().large().icon(IconName::Star)),
+            )
+            .into_any_element()
+    }
+}
@@ -0,0 +1,522 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::list::ListItem;
+use gpui_component::tree::{TreeItem, TreeState, tree};
+use gpui_component::*;
+
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+
+/// A file or directory found while walking the filesystem
+#[derive(Clone)]
+struct FsNode {
+    path: PathBuf,
+    name: SharedString,
+    is_dir: bool,
+}
+
+/// Read the direct children of a directory, folders first, then by name
+fn read_children(dir: &Path) -> Vec<FsNode> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut children = entries
+        .flatten()
+        .map(|entry| FsNode {
+            path: entry.path(),
+            name: entry.file_name().to_string_lossy().to_string().into(),
+            is_dir: entry.file_type().is_ok_and(|kind| kind.is_dir()),
+        })
+        .collect::<Vec<_>>();
+    children.sort_by_key(|node| (!node.is_dir, node.name.to_lowercase()));
+    children
+}
+
+fn file_icon(name: &str) -> IconName {
+    let extension = Path::new(name)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+
+    match extension.as_str() {
+        "rs" | "js" | "ts" | "py" | "go" | "c" | "h" | "cpp" | "sh" => IconName::SquareTerminal,
+        "toml" | "json" | "yaml" | "yml" | "lock" | "ini" => IconName::Settings2,
+        "md" | "txt" | "pdf" => IconName::BookOpen,
+        "png" | "jpg" | "jpeg" | "gif" | "svg" | "ico" => IconName::GalleryVerticalEnd,
+        _ if name.starts_with(".git") => IconName::GitHub,
+        _ => IconName::File,
+    }
+}
+
+/// Metadata of the node shown in the side panel
+struct NodeDetails {
+    path: PathBuf,
+    kind: &'static str,
+    size: Option<u64>,
+    modified: Option<DateTime<Local>>,
+    readonly: bool,
+}
+
+impl NodeDetails {
+    fn read(path: &Path) -> Option<Self> {
+        let metadata = fs::symlink_metadata(path).ok()?;
+        let kind = if metadata.is_symlink() {
+            "Symlink"
+        } else if metadata.is_dir() {
+            "Folder"
+        } else {
+            "File"
+        };
+
+        Some(Self {
+            path: path.to_path_buf(),
+            kind,
+            size: metadata.is_file().then_some(metadata.len()),
+            modified: metadata.modified().ok().map(DateTime::from),
+            readonly: metadata.permissions().readonly(),
+        })
+    }
+}
+
+fn format_size(bytes: u64) -> String {
+    match bytes {
+        b if b >= 1 << 20 => format!("{:.1} MB", b as f64 / (1 << 20) as f64),
+        b if b >= 1 << 10 => format!("{:.1} KB", b as f64 / (1 << 10) as f64),
+        b => format!("{} bytes", b),
+    }
+}
+
+pub struct TreeComponentView {
+    basic_tree: Entity<TreeState>,
+    explorer: Entity<TreeState>,
+    /// Folder at the top of the explorer
+    root: PathBuf,
+    /// Children of the folders loaded so far, a folder is read when first expanded
+    children: HashMap<PathBuf, Vec<FsNode>>,
+    loading: HashSet<PathBuf>,
+    /// Folder items of the last build, they hold whether a folder is expanded
+    folders: HashMap<SharedString, TreeItem>,
+    /// Items picked with Ctrl/Cmd-click, on top of the focused item of the tree
+    multi_selection: BTreeSet<SharedString>,
+    focused: Option<SharedString>,
+    details: Option<NodeDetails>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ComponentMeta for TreeComponentView {
+    const NAME: &'static str = "Tree";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataDisplay;
+    const DESCRIPTION: &'static str = "A hierarchical list of expandable items, like a file explorer. \nOnly the visible items are rendered, and the tree can be navigated with the arrow keys: \nup and down to move, left and right to collapse and expand, enter to toggle.";
+    const LINK: &'static str = "https://longbridge.github.io/gpui-component/docs/components/tree";
+
+    fn create(_window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let basic_tree = cx.new(|cx| {
+            TreeState::new(cx).items(vec![
+                TreeItem::new("src", "src")
+                    .expanded(true)
+                    .child(
+                        TreeItem::new("src/ui", "ui")
+                            .child(TreeItem::new("src/ui/button.rs", "button.rs"))
+                            .child(TreeItem::new("src/ui/icon.rs", "icon.rs")),
+                    )
+                    .child(TreeItem::new("src/lib.rs", "lib.rs")),
+                TreeItem::new("Cargo.toml", "Cargo.toml"),
+                TreeItem::new("Cargo.lock", "Cargo.lock").disabled(true),
+                TreeItem::new("README.md", "README.md"),
+            ])
+        });
+
+        let explorer = cx.new(|cx| TreeState::new(cx));
+        let _subscriptions = vec![cx.observe(&explorer, |this, _, cx| this.sync_explorer(cx))];
+
+        let mut this = Self {
+            basic_tree,
+            explorer,
+            root: PathBuf::new(),
+            children: HashMap::new(),
+            loading: HashSet::new(),
+            folders: HashMap::new(),
+            multi_selection: BTreeSet::new(),
+            focused: None,
+            details: None,
+            _subscriptions,
+        };
+        this.set_root(Self::crate_root(), cx);
+        this
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("basic_tree", "Basic Tree")
+                .render(|this, _, _| this.basic_tree()),
+            Self::example("file_explorer", "File Explorer")
+                .description("Folders are read from disk when first expanded. Ctrl/Cmd-click to select several items.")
+                .full_width()
+                .render(|this, _, cx| this.file_explorer(cx)),
+        ]
+    }
+}
+
+impl TreeComponentView {
+    /// Root of this crate when built from source, the working directory otherwise
+    fn crate_root() -> PathBuf {
+        let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        if manifest_dir.is_dir() {
+            manifest_dir
+        } else {
+            std::env::current_dir().unwrap_or_default()
+        }
+    }
+
+    fn set_root(&mut self, root: PathBuf, cx: &mut Context<Self>) {
+        self.root = root;
+        self.children.clear();
+        self.loading.clear();
+        self.folders.clear();
+        self.multi_selection.clear();
+        self.focused = None;
+        self.details = None;
+        self.rebuild(cx);
+        self.load_children(self.root.clone(), cx);
+    }
+
+    fn open_folder(&mut self, cx: &mut Context<Self>) {
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: false,
+            directories: true,
+            multiple: false,
+            prompt: Some("Open".into()),
+        });
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let Ok(Ok(Some(paths))) = paths.await else {
+                return;
+            };
+            if let Some(root) = paths.into_iter().next() {
+                this.update(cx, |this, cx| this.set_root(root, cx)).ok();
+            }
+        })
+        .detach();
+    }
+
+    /// Build the tree item of a node, keeping whether it was expanded in the last build
+    fn build_item(&mut self, node: &FsNode) -> TreeItem {
+        let id: SharedString = node.path.to_string_lossy().to_string().into();
+        let mut item = TreeItem::new(id.clone(), node.name.clone());
+        if !node.is_dir {
+            return item;
+        }
+
+        // A tree item is a folder when it has children, so folders that are
+        // not read yet, or empty, get a disabled placeholder
+        item = match self.children.get(&node.path).cloned() {
+            Some(children) if !children.is_empty() => {
+                item.children(children.iter().map(|child| self.build_item(child)))
+            }
+            Some(_) => item.child(TreeItem::new(format!("{}#empty", id), "Empty").disabled(true)),
+            None => item.child(TreeItem::new(format!("{}#loading", id), "Loading…").disabled(true)),
+        };
+
+        let expanded = match self.folders.get(&id) {
+            Some(previous) => previous.is_expanded(),
+            None => node.path == self.root,
+        };
+        item = item.expanded(expanded);
+        self.folders.insert(id, item.clone());
+        item
+    }
+
+    fn rebuild(&mut self, cx: &mut Context<Self>) {
+        let name = self
+            .root
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| self.root.display().to_string());
+        let root = self.build_item(&FsNode {
+            path: self.root.clone(),
+            name: name.into(),
+            is_dir: true,
+        });
+
+        // Setting the items clears the selection, find the focused item again
+        let selected_ix = self.focused.as_ref().and_then(|focused| {
+            let mut visible = Vec::new();
+            flatten_visible(&root, &mut visible);
+            visible.iter().position(|id| id == focused)
+        });
+
+        self.explorer.update(cx, |explorer, cx| {
+            explorer.set_items(vec![root], cx);
+            explorer.set_selected_index(selected_ix, cx);
+        });
+    }
+
+    fn load_children(&mut self, dir: PathBuf, cx: &mut Context<Self>) {
+        if !self.loading.insert(dir.clone()) {
+            return;
+        }
+
+        cx.spawn(async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+            let children = cx
+                .background_spawn({
+                    let dir = dir.clone();
+                    async move { read_children(&dir) }
+                })
+                .await;
+
+            this.update(cx, |this, cx| {
+                // Dropped if the root changed in the meantime
+                if this.loading.remove(&dir) {
+                    this.children.insert(dir, children);
+                    this.rebuild(cx);
+                }
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    /// Load the folders expanded since the last sync and follow the focused item
+    fn sync_explorer(&mut self, cx: &mut Context<Self>) {
+        let expanded = self
+            .folders
+            .iter()
+            .filter(|(_, item)| item.is_expanded())
+            .map(|(id, _)| PathBuf::from(id.as_ref()))
+            .filter(|path| !self.children.contains_key(path))
+            .collect::<Vec<_>>();
+        for dir in expanded {
+            self.load_children(dir, cx);
+        }
+
+        let focused = self
+            .explorer
+            .read(cx)
+            .selected_entry()
+            .map(|entry| entry.item().id.clone());
+        if focused.is_some() && focused != self.focused {
+            self.details = focused
+                .as_ref()
+                .and_then(|id| NodeDetails::read(Path::new(id.as_ref())));
+            self.focused = focused;
+        }
+        cx.notify();
+    }
+
+    fn toggle_multi_selection(&mut self, id: SharedString, additive: bool, cx: &mut Context<Self>) {
+        if additive {
+            if !self.multi_selection.remove(&id) {
+                self.multi_selection.insert(id);
+            }
+        } else {
+            self.multi_selection.clear();
+            self.multi_selection.insert(id);
+        }
+        cx.notify();
+    }
+
+    // Example code for the Tree component
+
+    fn basic_tree(&self) -> AnyElement {
+        div()
+            .h(px(240.))
+            .border_1()
+            .rounded_md()
+            .child(tree(
+                &self.basic_tree,
+                |ix, entry, _selected, _window, _cx| {
+                    let icon = if !entry.is_folder() {
+                        IconName::File
+                    } else if entry.is_expanded() {
+                        IconName::FolderOpen
+                    } else {
+                        IconName::FolderClosed
+                    };
+
+                    ListItem::new(ix)
+                        .pl(px(12. + 16. * entry.depth() as f32))
+                        .child(
+                            h_flex()
+                                .gap_2()
+                                .child(Icon::new(icon).small())
+                                .child(entry.item().label.clone()),
+                        )
+                },
+            ))
+            .into_any_element()
+    }
+
+    fn file_explorer(&self, cx: &mut Context<Self>) -> AnyElement {
+        let view = cx.entity().downgrade();
+
+        let explorer = tree(&self.explorer, move |ix, entry, _selected, _window, cx| {
+            let item = entry.item();
+            let icon = if !entry.is_folder() {
+                file_icon(&item.label)
+            } else if entry.is_expanded() {
+                IconName::FolderOpen
+            } else {
+                IconName::FolderClosed
+            };
+            let multi_selected = view
+                .upgrade()
+                .is_some_and(|view| view.read(cx).multi_selection.contains(&item.id));
+
+            let view = view.clone();
+            let id = item.id.clone();
+            ListItem::new(ix)
+                .pl(px(12. + 16. * entry.depth() as f32))
+                .secondary_selected(multi_selected)
+                .child(
+                    h_flex()
+                        .gap_2()
+                        .child(Icon::new(icon).small())
+                        .child(item.label.clone()),
+                )
+                .when(!entry.is_disabled(), |this| {
+                    this.on_click(move |event, _, cx| {
+                        let additive = event.modifiers().secondary();
+                        view.update(cx, |view, cx| {
+                            view.toggle_multi_selection(id.clone(), additive, cx);
+                        })
+                        .ok();
+                    })
+                })
+        });
+
+        v_flex()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("tree-crate-root")
+                            .label("Crate root")
+                            .small()
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.set_root(Self::crate_root(), cx);
+                            })),
+                    )
+                    .child(
+                        Button::new("tree-open-folder")
+                            .icon(IconName::FolderOpen)
+                            .label("Open folder…")
+                            .small()
+                            .on_click(cx.listener(|this, _, _, cx| this.open_folder(cx))),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(self.root.display().to_string()),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .h(px(400.))
+                    .gap_4()
+                    .child(
+                        div()
+                            .flex_1()
+                            .h_full()
+                            .border_1()
+                            .border_color(cx.theme().border)
+                            .rounded_md()
+                            .child(explorer),
+                    )
+                    .child(self.render_details(cx)),
+            )
+            .into_any_element()
+    }
+
+    fn render_details(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let row = |label: &'static str, value: String| {
+            v_flex()
+                .gap_0p5()
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(label),
+                )
+                .child(div().text_sm().child(value))
+        };
+
+        v_flex()
+            .w(px(280.))
+            .h_full()
+            .gap_3()
+            .p_3()
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded_md()
+            .map(|this| match &self.details {
+                Some(details) => this
+                    .child(row("Path", details.path.display().to_string()))
+                    .child(row("Kind", details.kind.to_string()))
+                    .when_some(details.size, |this, size| {
+                        this.child(row("Size", format_size(size)))
+                    })
+                    .when_some(details.path.extension(), |this, extension| {
+                        this.child(row("Extension", extension.to_string_lossy().to_string()))
+                    })
+                    .when_some(details.modified, |this, modified| {
+                        this.child(row(
+                            "Modified",
+                            modified.format("%Y-%m-%d %H:%M:%S").to_string(),
+                        ))
+                    })
+                    .child(row(
+                        "Permissions",
+                        if details.readonly {
+                            "Read only"
+                        } else {
+                            "Read and write"
+                        }
+                        .to_string(),
+                    ))
+                    .when_some(self.children.get(&details.path), |this, children| {
+                        this.child(row("Items", children.len().to_string()))
+                    }),
+                None => this.child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child("Select an item to see its details"),
+                ),
+            })
+            .when(self.multi_selection.len() > 1, |this| {
+                this.child(row(
+                    "Selection",
+                    format!("{} items selected", self.multi_selection.len()),
+                ))
+                .child(
+                    Button::new("tree-clear-selection")
+                        .label("Clear selection")
+                        .small()
+                        .ghost()
+                        .on_click(cx.listener(|this, _, _, cx| {
+                            this.multi_selection.clear();
+                            cx.notify();
+                        })),
+                )
+            })
+    }
+}
+
+/// Ids of the items of `item` in display order, the children of collapsed folders are hidden
+fn flatten_visible(item: &TreeItem, ids: &mut Vec<SharedString>) {
+    ids.push(item.id.clone());
+    if item.is_expanded() {
+        for child in &item.children {
+            flatten_visible(child, ids);
+        }
+    }
+}
@@ -0,0 +1,397 @@
+use std::ops::Range;
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::*;
+use gpui_component::button::Button;
+use gpui_component::input::{Input, InputEvent, InputState};
+use gpui_component::{
+    ActiveTheme, Sizable, VirtualListScrollHandle, h_flex, h_virtual_list, v_flex, v_virtual_list,
+};
+
+use crate::models::{ComponentCategory, ComponentMeta, Example};
+
+/// Items of the large example
+const LARGE_ITEMS: usize = 1_000_000;
+
+/// Items added at once by the append and prepend buttons
+const BATCH_SIZE: usize = 1_000;
+
+const HEADER_HEIGHT: f32 = 32.;
+const LINE_HEIGHT: f32 = 20.;
+
+const SAMPLE_LINES: [&str; 4] = [
+    "Only the items inside the viewport are laid out and painted.",
+    "Each item has its own height, given up front to the list.",
+    "Scrolling a million items costs the same as scrolling ten.",
+    "Sizes are summed once, then reused until they change.",
+];
+
+/// Number of text lines of an item, derived from its id so heights are stable
+fn item_lines(id: i64) -> usize {
+    ((id as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15) >> 62) as usize + 1
+}
+
+fn item_size(id: i64) -> Size<Pixels> {
+    size(
+        px(0.),
+        px(HEADER_HEIGHT + LINE_HEIGHT * item_lines(id) as f32),
+    )
+}
+
+/// Format a count with thousands separators, e.g. 1,000,000
+fn group_digits(value: usize) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::new();
+    for (ix, digit) in digits.chars().enumerate() {
+        if ix > 0 && (digits.len() - ix).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// A list of variable height items with controls to jump, scroll and grow it
+///
+/// The virtual list renders its items through a view, so the items and the
+/// scroll state live here rather than in [`VirtualListComponentView`].
+struct VirtualFeed {
+    ids: Vec<i64>,
+    item_sizes: Rc<Vec<Size<Pixels>>>,
+    scroll_handle: VirtualListScrollHandle,
+    index_input: Entity<InputState>,
+    /// Range of the items laid out in the last frame
+    laid_out: Range<usize>,
+    _scroll_task: Option<Task<()>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl VirtualFeed {
+    fn new(count: usize, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let ids = (0..count as i64).collect::<Vec<_>>();
+        let index_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("Index")
+                .validate(|text, _| text.chars().all(|c| c.is_ascii_digit()))
+        });
+        let _subscriptions = vec![cx.subscribe_in(
+            &index_input,
+            window,
+            |this, _, event: &InputEvent, _, cx| {
+                if let InputEvent::PressEnter { .. } = event {
+                    this.jump(false, cx);
+                }
+            },
+        )];
+
+        Self {
+            item_sizes: Rc::new(ids.iter().map(|id| item_size(*id)).collect()),
+            ids,
+            scroll_handle: VirtualListScrollHandle::new(),
+            index_input,
+            laid_out: 0..0,
+            _scroll_task: None,
+            _subscriptions,
+        }
+    }
+
+    /// Top of the item at `ix`, from the top of the list
+    fn item_origin(&self, ix: usize) -> f32 {
+        self.item_sizes[..ix]
+            .iter()
+            .map(|size| f32::from(size.height))
+            .sum()
+    }
+
+    /// Scroll to the index typed in the input, animated if `smooth`
+    fn jump(&mut self, smooth: bool, cx: &mut Context<Self>) {
+        let Ok(ix) = self.index_input.read(cx).value().parse::<usize>() else {
+            return;
+        };
+        let ix = ix.min(self.ids.len().saturating_sub(1));
+
+        if !smooth {
+            self._scroll_task = None;
+            self.scroll_handle.scroll_to_item(ix, ScrollStrategy::Top);
+            cx.notify();
+            return;
+        }
+
+        let content_height = self.item_origin(self.ids.len());
+        let viewport_height = f32::from(self.scroll_handle.bounds().size.height);
+        let target = self
+            .item_origin(ix)
+            .min((content_height - viewport_height).max(0.));
+        let start = -f32::from(self.scroll_handle.offset().y);
+
+        // Replacing the task cancels a scroll still running
+        self._scroll_task = Some(cx.spawn(
+            async move |this: WeakEntity<Self>, cx: &mut AsyncApp| {
+                const STEPS: u32 = 24;
+                for step in 1..=STEPS {
+                    cx.background_executor()
+                        .timer(Duration::from_millis(16))
+                        .await;
+
+                    // Ease out, fast first then slowing down on the target
+                    let t = step as f32 / STEPS as f32;
+                    let y = start + (target - start) * (1. - (1. - t).powi(3));
+                    let updated = this.update(cx, |this, cx| {
+                        this.scroll_handle.set_offset(point(px(0.), px(-y)));
+                        cx.notify();
+                    });
+                    if updated.is_err() {
+                        break;
+                    }
+                }
+            },
+        ));
+    }
+
+    fn append(&mut self, cx: &mut Context<Self>) {
+        let next = self.ids.last().map_or(0, |id| id + 1);
+        let added = next..next + BATCH_SIZE as i64;
+        // Only the new sizes are computed, the list is only copied if the
+        // last frame still holds it
+        Rc::make_mut(&mut self.item_sizes).extend(added.clone().map(item_size));
+        self.ids.extend(added);
+        cx.notify();
+    }
+
+    /// Add items above the first one, keeping the visible items in place
+    fn prepend(&mut self, cx: &mut Context<Self>) {
+        let first = self.ids.first().copied().unwrap_or(0);
+        let added = (first - BATCH_SIZE as i64..first).collect::<Vec<_>>();
+        let added_sizes = added.iter().map(|id| item_size(*id)).collect::<Vec<_>>();
+        let added_height = added_sizes
+            .iter()
+            .map(|size| f32::from(size.height))
+            .sum::<f32>();
+
+        Rc::make_mut(&mut self.item_sizes).splice(0..0, added_sizes);
+        self.ids.splice(0..0, added);
+        self.laid_out = self.laid_out.start + BATCH_SIZE..self.laid_out.end + BATCH_SIZE;
+
+        // Move the scroll position down by the height of the new items, so the
+        // item at the top of the viewport stays at the top
+        let offset = self.scroll_handle.offset();
+        self.scroll_handle
+            .set_offset(point(offset.x, offset.y - px(added_height)));
+        cx.notify();
+    }
+
+    fn render_items(
+        &mut self,
+        visible_range: Range<usize>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Vec<impl IntoElement + use<>> {
+        // The counter is rendered before the items, draw it again once they changed
+        if visible_range != self.laid_out {
+            self.laid_out = visible_range.clone();
+            cx.on_next_frame(window, |_, _, cx| cx.notify());
+        }
+
+        visible_range
+            .map(|ix| {
+                let id = self.ids[ix];
+                v_flex()
+                    .id(ix)
+                    .h(self.item_sizes[ix].height)
+                    .px_3()
+                    .overflow_hidden()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .child(
+                        h_flex()
+                            .h(px(HEADER_HEIGHT))
+                            .gap_2()
+                            .child(
+                                div()
+                                    .font_weight(FontWeight::SEMIBOLD)
+                                    .child(format!("#{}", group_digits(ix))),
+                            )
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(format!("id {} · {} lines", id, item_lines(id))),
+                            ),
+                    )
+                    .children((0..item_lines(id)).map(|line| {
+                        div()
+                            .h(px(LINE_HEIGHT))
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(SAMPLE_LINES[line])
+                    }))
+            })
+            .collect()
+    }
+}
+
+impl Render for VirtualFeed {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .w(px(120.))
+                            .child(Input::new(&self.index_input).small()),
+                    )
+                    .child(
+                        Button::new("virtual-list-jump")
+                            .label("Jump")
+                            .small()
+                            .on_click(cx.listener(|this, _, _, cx| this.jump(false, cx))),
+                    )
+                    .child(
+                        Button::new("virtual-list-smooth-scroll")
+                            .label("Smooth scroll")
+                            .small()
+                            .on_click(cx.listener(|this, _, _, cx| this.jump(true, cx))),
+                    )
+                    .child(
+                        Button::new("virtual-list-prepend")
+                            .label(format!("Prepend {}", group_digits(BATCH_SIZE)))
+                            .small()
+                            .outline()
+                            .on_click(cx.listener(|this, _, _, cx| this.prepend(cx))),
+                    )
+                    .child(
+                        Button::new("virtual-list-append")
+                            .label(format!("Append {}", group_digits(BATCH_SIZE)))
+                            .small()
+                            .outline()
+                            .on_click(cx.listener(|this, _, _, cx| this.append(cx))),
+                    ),
+            )
+            .child(
+                div()
+                    .relative()
+                    .h(px(400.))
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .rounded_md()
+                    .child(
+                        v_virtual_list(
+                            cx.entity(),
+                            "virtual-feed",
+                            self.item_sizes.clone(),
+                            Self::render_items,
+                        )
+                        .track_scroll(&self.scroll_handle),
+                    )
+                    .child(
+                        // Overlay showing what the last frame laid out
+                        div()
+                            .absolute()
+                            .top_2()
+                            .right_4()
+                            .px_2()
+                            .py_1()
+                            .rounded_md()
+                            .bg(cx.theme().secondary)
+                            .text_xs()
+                            .child(format!(
+                                "Laid out: {} of {} items · #{}–#{}",
+                                self.laid_out.len(),
+                                group_digits(self.ids.len()),
+                                group_digits(self.laid_out.start),
+                                group_digits(self.laid_out.end.saturating_sub(1)),
+                            )),
+                    ),
+            )
+    }
+}
+
+/// A horizontal strip of cards with different widths
+struct CardStrip {
+    item_sizes: Rc<Vec<Size<Pixels>>>,
+}
+
+impl CardStrip {
+    fn new() -> Self {
+        Self {
+            item_sizes: Rc::new(
+                (0..1_000)
+                    .map(|ix| size(px(80. + 40. * (ix % 4) as f32), px(0.)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl Render for CardStrip {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        div().h(px(96.)).child(h_virtual_list(
+            cx.entity(),
+            "card-strip",
+            self.item_sizes.clone(),
+            |this, visible_range, _, cx| {
+                visible_range
+                    .map(|ix| {
+                        div().w(this.item_sizes[ix].width).h_full().p_1().child(
+                            div()
+                                .size_full()
+                                .flex()
+                                .items_center()
+                                .justify_center()
+                                .rounded_md()
+                                .bg(cx.theme().secondary)
+                                .child(format!("Card {}", ix)),
+                        )
+                    })
+                    .collect()
+            },
+        ))
+    }
+}
+
+pub struct VirtualListComponentView {
+    feed: Entity<VirtualFeed>,
+    card_strip: Entity<CardStrip>,
+}
+
+impl ComponentMeta for VirtualListComponentView {
+    const NAME: &'static str = "Virtual List";
+    const CATEGORY: ComponentCategory = ComponentCategory::DataDisplay;
+    const DESCRIPTION: &'static str = "A list rendering only the visible items, where each item can have its own height or width. \nThe size of every item is given up front, \nso the list can place any item without laying out the ones before it.";
+    const LINK: &'static str =
+        "https://longbridge.github.io/gpui-component/docs/components/virtual-list";
+
+    fn create(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            feed: cx.new(|cx| VirtualFeed::new(LARGE_ITEMS, window, cx)),
+            card_strip: cx.new(|_| CardStrip::new()),
+        }
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Self::example("variable_height_items", "1,000,000 Variable Height Items")
+                .description("Type an index and press Enter to jump to it, or smooth scroll there. Prepending keeps the visible items in place.")
+                .full_width()
+                .render(|this, _, _| this.variable_height_items()),
+            Self::example("horizontal_list", "Horizontal List")
+                .full_width()
+                .render(|this, _, _| this.horizontal_list()),
+        ]
+    }
+}
+
+impl VirtualListComponentView {
+    // Example code for the Virtual List component
+
+    fn variable_height_items(&self) -> AnyElement {
+        self.feed.clone().into_any_element()
+    }
+
+    fn horizontal_list(&self) -> AnyElement {
+        self.card_strip.clone().into_any_element()
+    }
+}
@@ -0,0 +1,198 @@
+use std::{fs, ops::RangeInclusive, path::PathBuf};
+
+use anyhow::Context as _;
+use gpui::{App, Global, Window};
+use gpui_component::input::TabSize;
+use gpui_component::{Theme, ThemeMode};
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+/// Directory name of the sample application in the user config dir
+pub const APP_DIR: &str = "gpui-component-sample";
+
+/// Widths of the expanded sidebar offered in the settings, in pixels
+pub const SIDEBAR_WIDTH_RANGE: RangeInclusive<f32> = 160.0..=400.0;
+
+/// Tab sizes offered in the settings
+pub const TAB_SIZE_RANGE: RangeInclusive<usize> = 1..=8;
+
+/// Theme mode chosen by the user
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Display,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(ascii_case_insensitive)]
+pub enum ThemePreference {
+    Light,
+    #[default]
+    Dark,
+    /// Follow the appearance of the operating system
+    System,
+}
+
+impl ThemePreference {
+    /// Apply the preference to the global theme
+    pub fn apply(self, window: Option<&mut Window>, cx: &mut App) {
+        match self {
+            ThemePreference::Light => Theme::change(ThemeMode::Light, window, cx),
+            ThemePreference::Dark => Theme::change(ThemeMode::Dark, window, cx),
+            ThemePreference::System => Theme::sync_system_appearance(window, cx),
+        }
+    }
+}
+
+/// User preferences of the gallery, persisted as JSON in the user config dir
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    pub theme: ThemePreference,
+    /// Name of the theme used in light mode, the default light theme if unset
+    pub light_theme: Option<String>,
+    /// Name of the theme used in dark mode, the default dark theme if unset
+    pub dark_theme: Option<String>,
+    /// Width of the expanded sidebar in pixels
+    pub sidebar_width: f32,
+    /// Spaces inserted by Tab in the code editors
+    pub editor_tab_size: usize,
+    /// Keep adding points to the live charts
+    pub chart_animation: bool,
+    /// Theme mode given on the command line, used for this launch only
+    #[serde(skip)]
+    pub launch_theme: Option<ThemePreference>,
+    /// Only loaded preferences are saved, defaults stay in memory, e.g. in tests
+    #[serde(skip)]
+    persistent: bool,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            theme: ThemePreference::default(),
+            light_theme: None,
+            dark_theme: None,
+            sidebar_width: 200.,
+            editor_tab_size: 4,
+            chart_animation: true,
+            launch_theme: None,
+            persistent: false,
+        }
+    }
+}
+
+impl Global for Preferences {}
+
+impl Preferences {
+    /// Path of the preferences file, `None` if the platform has no config dir
+    pub fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join(APP_DIR).join("preferences.json"))
+    }
+
+    /// Load the preferences file, falling back to the defaults if it is
+    /// missing or unreadable
+    pub fn load() -> Self {
+        let preferences: Self = Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        Self {
+            persistent: true,
+            ..preferences.clamped()
+        }
+    }
+
+    /// Bring hand-edited values back within the ranges of the settings
+    fn clamped(self) -> Self {
+        Self {
+            sidebar_width: self
+                .sidebar_width
+                .clamp(*SIDEBAR_WIDTH_RANGE.start(), *SIDEBAR_WIDTH_RANGE.end()),
+            editor_tab_size: self
+                .editor_tab_size
+                .clamp(*TAB_SIZE_RANGE.start(), *TAB_SIZE_RANGE.end()),
+            ..self
+        }
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        if !self.persistent {
+            return Ok(());
+        }
+
+        let path = Self::path().context("no config directory on this platform")?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    pub fn global(cx: &App) -> &Self {
+        cx.global::<Self>()
+    }
+
+    /// Theme mode in use, the one given on the command line if any
+    pub fn theme_mode(&self) -> ThemePreference {
+        self.launch_theme.unwrap_or(self.theme)
+    }
+
+    /// Choose the theme mode, replacing the one given on the command line
+    pub fn set_theme(&mut self, theme: ThemePreference) {
+        self.theme = theme;
+        self.launch_theme = None;
+    }
+
+    /// Indentation of the code editors
+    pub fn tab_size(&self) -> TabSize {
+        TabSize {
+            tab_size: self.editor_tab_size,
+            hard_tabs: false,
+        }
+    }
+
+    /// Update the global preferences and write them to disk
+    pub fn update(cx: &mut App, f: impl FnOnce(&mut Self)) {
+        let preferences = cx.global_mut::<Self>();
+        f(preferences);
+        if let Err(err) = preferences.save() {
+            eprintln!("Failed to save preferences: {:#}", err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_json(json: &str) -> Preferences {
+        serde_json::from_str::<Preferences>(json).unwrap().clamped()
+    }
+
+    #[test]
+    fn clamps_out_of_range_values() {
+        let preferences = from_json(r#"{"sidebar_width": -50, "editor_tab_size": 0}"#);
+        assert_eq!(preferences.sidebar_width, 160.);
+        assert_eq!(preferences.editor_tab_size, 1);
+
+        let preferences = from_json(r#"{"sidebar_width": 5000, "editor_tab_size": 64}"#);
+        assert_eq!(preferences.sidebar_width, 400.);
+        assert_eq!(preferences.editor_tab_size, 8);
+    }
+
+    #[test]
+    fn keeps_values_in_range() {
+        let preferences = from_json(r#"{"sidebar_width": 240, "editor_tab_size": 2}"#);
+        assert_eq!(preferences.sidebar_width, 240.);
+        assert_eq!(preferences.editor_tab_size, 2);
+        assert_eq!(from_json("{}"), Preferences::default());
+    }
+}
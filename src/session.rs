@@ -0,0 +1,93 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::Context as _;
+use gpui::{App, Bounds, Global, Pixels, WindowBounds};
+use serde::{Deserialize, Serialize};
+
+use crate::models::Components;
+use crate::preferences::APP_DIR;
+
+/// State of the last run, restored on launch so the gallery reopens where it was left
+///
+/// Unlike [`Preferences`](crate::preferences::Preferences), which holds choices
+/// like the theme, the session only describes where the user was and can be
+/// discarded with `--reset-session`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+    /// Last shown component, the welcome page if unset
+    pub component: Option<String>,
+    pub sidebar_collapsed: bool,
+    pub window_bounds: Option<Bounds<Pixels>>,
+    pub window_maximized: bool,
+    /// Vertical scroll offset of the examples of `component`
+    pub scroll_offset: f32,
+    /// Only a loaded session is saved, a default one stays in memory, e.g. in tests
+    #[serde(skip)]
+    persistent: bool,
+}
+
+impl Global for Session {}
+
+impl Session {
+    /// Path of the session file, `None` if the platform has no config dir
+    pub fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join(APP_DIR).join("session.json"))
+    }
+
+    /// Load the last session, falling back to a fresh one if it is missing or unreadable
+    pub fn load() -> Self {
+        let session: Self = Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        Self {
+            persistent: true,
+            ..session
+        }
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        if !self.persistent {
+            return Ok(());
+        }
+
+        let path = Self::path().context("no config directory on this platform")?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    /// Delete the session file so the next launch starts fresh
+    pub fn reset() -> anyhow::Result<()> {
+        match Self::path() {
+            Some(path) if path.exists() => Ok(fs::remove_file(path)?),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn global(cx: &App) -> &Self {
+        cx.global::<Self>()
+    }
+
+    pub fn component(&self) -> Option<Components> {
+        self.component.as_deref()?.parse().ok()
+    }
+
+    /// Window bounds to restore, if the last session recorded any
+    pub fn window_bounds(&self) -> Option<WindowBounds> {
+        let bounds = self.window_bounds?;
+        Some(if self.window_maximized {
+            WindowBounds::Maximized(bounds)
+        } else {
+            WindowBounds::Windowed(bounds)
+        })
+    }
+
+    pub fn set_window_bounds(&mut self, bounds: WindowBounds) {
+        self.window_bounds = Some(bounds.get_bounds());
+        self.window_maximized = matches!(bounds, WindowBounds::Maximized(_));
+    }
+}
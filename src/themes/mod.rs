@@ -0,0 +1,248 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    time::Duration,
+};
+
+use anyhow::Context as _;
+use gpui::{App, Global, Hsla, Window};
+use gpui_component::{Colorize, Theme, ThemeConfig, ThemeRegistry, ThemeSet};
+use notify::Watcher as _;
+
+use crate::preferences::Preferences;
+
+mod tokens;
+pub use tokens::{THEME_TOKENS, ThemeToken};
+
+/// Directory the custom themes are loaded from, relative to the working directory
+pub const THEMES_DIR: &str = "themes";
+
+/// Custom themes loaded from `.json` and `.toml` files in [`THEMES_DIR`]
+///
+/// The files use the gpui-component theme format, a theme set with a list of
+/// themes whose colors are keyed like `primary.background` or `chart.1`.
+#[derive(Default)]
+pub struct ThemeLibrary {
+    themes: Vec<Rc<ThemeConfig>>,
+}
+
+impl Global for ThemeLibrary {}
+
+impl ThemeLibrary {
+    pub fn global(cx: &App) -> &Self {
+        cx.global::<Self>()
+    }
+
+    /// Every selectable theme, the built-in light and dark themes first
+    pub fn themes(cx: &App) -> Vec<Rc<ThemeConfig>> {
+        let registry = ThemeRegistry::global(cx);
+        let mut themes = vec![
+            registry.default_light_theme().clone(),
+            registry.default_dark_theme().clone(),
+        ];
+        themes.extend(Self::global(cx).themes.iter().cloned());
+        themes
+    }
+
+    pub fn find(name: &str, cx: &App) -> Option<Rc<ThemeConfig>> {
+        Self::themes(cx)
+            .into_iter()
+            .find(|theme| theme.name.as_ref() == name)
+    }
+
+    /// Parse every theme file of `dir`, invalid files are reported and skipped
+    pub fn load_dir(dir: &Path) -> Vec<ThemeConfig> {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return Vec::new();
+        };
+
+        let mut paths = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                matches!(
+                    path.extension().and_then(|ext| ext.to_str()),
+                    Some("json" | "toml")
+                )
+            })
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        paths
+            .iter()
+            .filter_map(|path| match Self::parse_file(path) {
+                Ok(theme_set) => Some(theme_set.themes),
+                Err(err) => {
+                    eprintln!("Ignored invalid theme file: {:#}", err);
+                    None
+                }
+            })
+            .flatten()
+            .collect()
+    }
+
+    fn parse_file(path: &Path) -> anyhow::Result<ThemeSet> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let theme_set = if path.extension().is_some_and(|ext| ext == "toml") {
+            toml::from_str(&content).with_context(|| path.display().to_string())?
+        } else {
+            serde_json::from_str(&content).with_context(|| path.display().to_string())?
+        };
+        Ok(theme_set)
+    }
+
+    /// Reload the themes of `dir` and re-apply the active ones
+    pub fn reload(dir: &Path, cx: &mut App) {
+        let themes = Self::load_dir(dir).into_iter().map(Rc::new).collect();
+        cx.set_global(Self { themes });
+        select_themes(cx);
+        // Keep the current mode, the window appearance is not known here
+        Theme::change(Theme::global(cx).mode, None, cx);
+        cx.refresh_windows();
+    }
+
+    /// Reload the themes whenever a file of `dir` is created, changed or removed
+    pub fn watch(dir: PathBuf, cx: &mut App) -> anyhow::Result<()> {
+        let (tx, rx) = smol::channel::unbounded();
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+                if event.is_ok_and(|event| {
+                    event.kind.is_create() || event.kind.is_modify() || event.kind.is_remove()
+                }) {
+                    _ = tx.send_blocking(());
+                }
+            })?;
+        watcher.watch(&dir, notify::RecursiveMode::NonRecursive)?;
+
+        cx.spawn(async move |cx| {
+            // The watcher stops when dropped, keep it alive with the task
+            let _watcher = watcher;
+            while rx.recv().await.is_ok() {
+                // Editors emit several events per save, reload once they settle
+                cx.background_executor()
+                    .timer(Duration::from_millis(100))
+                    .await;
+                while rx.try_recv().is_ok() {}
+
+                if cx.update(|cx| Self::reload(&dir, cx)).is_err() {
+                    break;
+                }
+            }
+        })
+        .detach();
+
+        Ok(())
+    }
+}
+
+/// Load the custom themes and start watching them for changes
+pub fn init(cx: &mut App) {
+    let dir = PathBuf::from(THEMES_DIR);
+    let themes = ThemeLibrary::load_dir(&dir)
+        .into_iter()
+        .map(Rc::new)
+        .collect();
+    cx.set_global(ThemeLibrary { themes });
+
+    if dir.is_dir()
+        && let Err(err) = ThemeLibrary::watch(dir, cx)
+    {
+        eprintln!("Failed to watch the themes directory: {:#}", err);
+    }
+}
+
+/// Apply the light and dark themes and the theme mode chosen in the preferences
+pub fn apply(window: Option<&mut Window>, cx: &mut App) {
+    select_themes(cx);
+    Preferences::global(cx).theme_mode().apply(window, cx);
+}
+
+/// Use the light and dark themes chosen in the preferences, falling back to
+/// the default ones when they are unset or no longer exist
+fn select_themes(cx: &mut App) {
+    let preferences = Preferences::global(cx).clone();
+    let registry = ThemeRegistry::global(cx);
+    let default_light = registry.default_light_theme().clone();
+    let default_dark = registry.default_dark_theme().clone();
+
+    let light_theme = preferences
+        .light_theme
+        .as_deref()
+        .and_then(|name| ThemeLibrary::find(name, cx))
+        .filter(|theme| !theme.mode.is_dark())
+        .unwrap_or(default_light);
+    let dark_theme = preferences
+        .dark_theme
+        .as_deref()
+        .and_then(|name| ThemeLibrary::find(name, cx))
+        .filter(|theme| theme.mode.is_dark())
+        .unwrap_or(default_dark);
+
+    let theme = Theme::global_mut(cx);
+    theme.light_theme = light_theme;
+    theme.dark_theme = dark_theme;
+}
+
+/// Change a color of the active theme, every window is refreshed to show it
+pub fn set_token(token: &ThemeToken, color: Hsla, cx: &mut App) {
+    (token.set)(&mut Theme::global_mut(cx).colors, color);
+    cx.refresh_windows();
+}
+
+/// Save the colors of the active theme as `custom-<mode>.json` in [`THEMES_DIR`]
+/// and use it for the current mode, returns the path of the written file
+pub fn export_active_theme(cx: &mut App) -> anyhow::Result<PathBuf> {
+    let theme = Theme::global(cx);
+    let mode = theme.mode;
+    let name = if mode.is_dark() {
+        "Custom Dark"
+    } else {
+        "Custom Light"
+    };
+    let colors = THEME_TOKENS
+        .iter()
+        .map(|token| {
+            (
+                token.key.to_string(),
+                (token.get)(&theme.colors).to_hex().into(),
+            )
+        })
+        .collect::<serde_json::Map<_, _>>();
+    let theme_set = serde_json::json!({
+        "name": name,
+        "themes": [{ "name": name, "mode": mode, "colors": colors }],
+    });
+
+    let dir = PathBuf::from(THEMES_DIR);
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("custom-{}.json", mode.name()));
+    fs::write(&path, serde_json::to_string_pretty(&theme_set)?)
+        .with_context(|| format!("failed to write {}", path.display()))?;
+
+    // Select the exported theme before reloading, so the reload applies the
+    // same colors instead of discarding the edits
+    Preferences::update(cx, |preferences| {
+        if mode.is_dark() {
+            preferences.dark_theme = Some(name.to_string());
+        } else {
+            preferences.light_theme = Some(name.to_string());
+        }
+    });
+    ThemeLibrary::reload(&dir, cx);
+
+    Ok(path)
+}
+
+/// Go back to the built-in theme of the current mode, discarding color edits
+pub fn reset_to_default(window: Option<&mut Window>, cx: &mut App) {
+    let is_dark = Theme::global(cx).is_dark();
+    Preferences::update(cx, |preferences| {
+        if is_dark {
+            preferences.dark_theme = None;
+        } else {
+            preferences.light_theme = None;
+        }
+    });
+    apply(window, cx);
+}
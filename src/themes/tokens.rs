@@ -0,0 +1,136 @@
+use gpui::Hsla;
+use gpui_component::ThemeColor;
+
+/// A color of the theme, with the key naming it in theme files
+pub struct ThemeToken {
+    /// Key of the color in the `colors` section of a theme file, e.g. `primary.background`
+    pub key: &'static str,
+    pub get: fn(&ThemeColor) -> Hsla,
+    pub set: fn(&mut ThemeColor, Hsla),
+}
+
+macro_rules! theme_tokens {
+    ($($field:ident => $key:literal),* $(,)?) => {
+        /// Every color of [`ThemeColor`], in declaration order
+        pub static THEME_TOKENS: &[ThemeToken] = &[
+            $(ThemeToken {
+                key: $key,
+                get: |colors| colors.$field,
+                set: |colors, color| colors.$field = color,
+            }),*
+        ];
+    };
+}
+
+// The field names match the `ThemeConfigColors` fields of gpui-component,
+// the keys their serde names
+theme_tokens! {
+    accent => "accent.background",
+    accent_foreground => "accent.foreground",
+    accordion => "accordion.background",
+    accordion_hover => "accordion.hover.background",
+    background => "background",
+    border => "border",
+    group_box => "group_box.background",
+    group_box_foreground => "group_box.foreground",
+    caret => "caret",
+    chart_1 => "chart.1",
+    chart_2 => "chart.2",
+    chart_3 => "chart.3",
+    chart_4 => "chart.4",
+    chart_5 => "chart.5",
+    danger => "danger.background",
+    danger_active => "danger.active.background",
+    danger_foreground => "danger.foreground",
+    danger_hover => "danger.hover.background",
+    description_list_label => "description_list.label.background",
+    description_list_label_foreground => "description_list.label.foreground",
+    drag_border => "drag.border",
+    drop_target => "drop_target.background",
+    foreground => "foreground",
+    info => "info.background",
+    info_active => "info.active.background",
+    info_foreground => "info.foreground",
+    info_hover => "info.hover.background",
+    input => "input.border",
+    link => "link",
+    link_active => "link.active",
+    link_hover => "link.hover",
+    list => "list.background",
+    list_active => "list.active.background",
+    list_active_border => "list.active.border",
+    list_even => "list.even.background",
+    list_head => "list.head.background",
+    list_hover => "list.hover.background",
+    muted => "muted.background",
+    muted_foreground => "muted.foreground",
+    popover => "popover.background",
+    popover_foreground => "popover.foreground",
+    primary => "primary.background",
+    primary_active => "primary.active.background",
+    primary_foreground => "primary.foreground",
+    primary_hover => "primary.hover.background",
+    progress_bar => "progress.bar.background",
+    ring => "ring",
+    scrollbar => "scrollbar.background",
+    scrollbar_thumb => "scrollbar.thumb.background",
+    scrollbar_thumb_hover => "scrollbar.thumb.hover.background",
+    secondary => "secondary.background",
+    secondary_active => "secondary.active.background",
+    secondary_foreground => "secondary.foreground",
+    secondary_hover => "secondary.hover.background",
+    selection => "selection.background",
+    sidebar => "sidebar.background",
+    sidebar_accent => "sidebar.accent.background",
+    sidebar_accent_foreground => "sidebar.accent.foreground",
+    sidebar_border => "sidebar.border",
+    sidebar_foreground => "sidebar.foreground",
+    sidebar_primary => "sidebar.primary.background",
+    sidebar_primary_foreground => "sidebar.primary.foreground",
+    skeleton => "skeleton.background",
+    slider_bar => "slider.background",
+    slider_thumb => "slider.thumb.background",
+    success => "success.background",
+    success_foreground => "success.foreground",
+    success_hover => "success.hover.background",
+    success_active => "success.active.background",
+    bullish => "bullish.background",
+    bearish => "bearish.background",
+    switch => "switch.background",
+    switch_thumb => "switch.thumb.background",
+    tab => "tab.background",
+    tab_active => "tab.active.background",
+    tab_active_foreground => "tab.active.foreground",
+    tab_bar => "tab_bar.background",
+    tab_bar_segmented => "tab_bar.segmented.background",
+    tab_foreground => "tab.foreground",
+    table => "table.background",
+    table_active => "table.active.background",
+    table_active_border => "table.active.border",
+    table_even => "table.even.background",
+    table_head => "table.head.background",
+    table_head_foreground => "table.head.foreground",
+    table_hover => "table.hover.background",
+    table_row_border => "table.row.border",
+    title_bar => "title_bar.background",
+    title_bar_border => "title_bar.border",
+    tiles => "tiles.background",
+    warning => "warning.background",
+    warning_active => "warning.active.background",
+    warning_hover => "warning.hover.background",
+    warning_foreground => "warning.foreground",
+    overlay => "overlay",
+    window_border => "window.border",
+    red => "base.red",
+    red_light => "base.red.light",
+    green => "base.green",
+    green_light => "base.green.light",
+    blue => "base.blue",
+    blue_light => "base.blue.light",
+    yellow => "base.yellow",
+    yellow_light => "base.yellow.light",
+    magenta => "base.magenta",
+    magenta_light => "base.magenta.light",
+    cyan => "base.cyan",
+    cyan_light => "base.cyan.light",
+}
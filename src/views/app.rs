@@ -3,29 +3,138 @@ use gpui_component::button::{Button, ButtonVariants};
 use gpui_component::{link::Link, sidebar::*, *};
 use strum::IntoEnumIterator;
 
-use super::AppTitleBar;
-use crate::models::Components;
+use super::{AppTitleBar, SelectTheme, SwitchTheme};
+use crate::models::{ComponentCategory, REGISTRY};
+use crate::preferences::{Preferences, ThemePreference};
+use crate::session::Session;
 use crate::sidebar::CustomSidebarToggleButton;
-use crate::views::MainPage;
+use crate::themes::{self, ThemeLibrary};
+use crate::views::{CommandPalette, MainPage, ToggleCommandPalette};
 
 pub struct MyApp {
     pub gpui_component_version: &'static str,
     pub sidebar_collapsed: bool,
     pub main_page: Entity<MainPage>,
+    focus_handle: FocusHandle,
+    _subscriptions: Vec<Subscription>,
 }
 
 impl MyApp {
-    pub fn new(cx: &mut Context<Self>) -> Self {
-        Self {
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        // Keep the app focused so global shortcuts like Ctrl+K are dispatched to it
+        let focus_handle = cx.focus_handle();
+        focus_handle.focus(window);
+
+        let _subscriptions = vec![
+            cx.observe_window_appearance(window, |_, window, cx| {
+                if Preferences::global(cx).theme_mode() == ThemePreference::System {
+                    Theme::sync_system_appearance(Some(window), cx);
+                }
+            }),
+            cx.observe_window_bounds(window, |_, window, cx| {
+                cx.global_mut::<Session>()
+                    .set_window_bounds(window.window_bounds());
+            }),
+            cx.on_app_quit(|this, cx| {
+                this.save_session(cx);
+                async {}
+            }),
+        ];
+
+        let this = cx.weak_entity();
+        window.on_window_should_close(cx, move |_, cx| {
+            _ = this.update(cx, |this, cx| this.save_session(cx));
+            true
+        });
+
+        let mut app = Self {
             gpui_component_version: "0.5.0",
             sidebar_collapsed: false,
-            main_page: cx.new(|cx| MainPage::new(cx)),
+            main_page: cx.new(MainPage::new),
+            focus_handle,
+            _subscriptions,
+        };
+        app.restore_session(window, cx);
+        app
+    }
+
+    /// Reopen the component page and sidebar state of the last session
+    fn restore_session(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let session = Session::global(cx).clone();
+        self.sidebar_collapsed = session.sidebar_collapsed;
+
+        let Some(component) = session.component() else {
+            return;
+        };
+        self.main_page.update(cx, |page, cx| {
+            page.show_component(component, window, cx);
+            if let Some(showcase) = page.showcase() {
+                showcase.update(cx, |showcase, cx| {
+                    showcase.set_scroll_offset(px(session.scroll_offset), cx);
+                });
+            }
+        });
+    }
+
+    fn save_session(&mut self, cx: &mut Context<Self>) {
+        let showcase = self.main_page.read(cx).showcase().map(|showcase| {
+            let showcase = showcase.read(cx);
+            (showcase.component, showcase.scroll_offset())
+        });
+
+        let session = cx.global_mut::<Session>();
+        session.component = showcase.map(|(component, _)| component.to_string());
+        session.scroll_offset = showcase.map_or(0., |(_, offset)| offset.into());
+        session.sidebar_collapsed = self.sidebar_collapsed;
+        if let Err(err) = session.save() {
+            eprintln!("Failed to save the session: {:#}", err);
         }
     }
 
+    fn switch_theme(&mut self, action: &SwitchTheme, window: &mut Window, cx: &mut Context<Self>) {
+        let preference = action.0;
+        preference.apply(Some(window), cx);
+        Preferences::update(cx, |preferences| preferences.set_theme(preference));
+        cx.notify();
+    }
+
+    fn select_theme(&mut self, action: &SelectTheme, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(theme) = ThemeLibrary::find(&action.0, cx) else {
+            return;
+        };
+
+        let name = Some(theme.name.to_string());
+        Preferences::update(cx, |preferences| {
+            if theme.mode.is_dark() {
+                preferences.dark_theme = name;
+            } else {
+                preferences.light_theme = name;
+            }
+            // Show the theme right away unless the mode follows the system
+            if preferences.theme_mode() != ThemePreference::System {
+                preferences.set_theme(if theme.mode.is_dark() {
+                    ThemePreference::Dark
+                } else {
+                    ThemePreference::Light
+                });
+            }
+        });
+        themes::apply(Some(window), cx);
+        cx.notify();
+    }
+
+    fn toggle_command_palette(
+        &mut self,
+        _: &ToggleCommandPalette,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        CommandPalette::toggle(self.main_page.clone(), window, cx);
+    }
+
     fn render_sidebar(&mut self, cx: &mut Context<Self>) -> impl IntoElement {
         Sidebar::new(Side::Left)
-            .w(px(200.))
+            .w(px(Preferences::global(cx).sidebar_width))
             .collapsed(self.sidebar_collapsed)
             .header(
                 SidebarHeader::new()
@@ -55,22 +164,33 @@ impl MyApp {
                     ),
             )
             .child(
-                SidebarMenu::new().child(
-                    SidebarMenuItem::new("Dashboard")
-                        .icon(IconName::LayoutDashboard)
-                        .active(true)
-                        .children(Components::iter().map(|component| {
-                            let main_page = self.main_page.clone();
-                            SidebarMenuItem::new(component.to_string())
-                                .active(true)
-                                .on_click(cx.listener(move |_this, _, window, cx| {
-                                    main_page.update(cx, |page, cx| {
-                                        page.show_component(component, window, cx);
-                                    });
-                                }))
-                        }))
-                        .default_open(true),
-                ),
+                SidebarMenu::new().children(ComponentCategory::iter().filter_map(|category| {
+                    let entries = REGISTRY
+                        .iter()
+                        .filter(|entry| entry.category == category)
+                        .collect::<Vec<_>>();
+                    if entries.is_empty() {
+                        return None;
+                    }
+
+                    Some(
+                        SidebarMenuItem::new(category.to_string())
+                            .icon(category.icon())
+                            .active(true)
+                            .children(entries.into_iter().map(|entry| {
+                                let main_page = self.main_page.clone();
+                                let component = entry.component;
+                                SidebarMenuItem::new(entry.name)
+                                    .active(true)
+                                    .on_click(cx.listener(move |_this, _, window, cx| {
+                                        main_page.update(cx, |page, cx| {
+                                            page.show_component(component, window, cx);
+                                        });
+                                    }))
+                            }))
+                            .default_open(true),
+                    )
+                })),
             )
             .footer(
                 SidebarFooter::new().pt_0().pb_0().child(
@@ -98,7 +218,11 @@ impl Render for MyApp {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         v_flex()
             .size_full()
-            .child(AppTitleBar::new())
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::toggle_command_palette))
+            .on_action(cx.listener(Self::switch_theme))
+            .on_action(cx.listener(Self::select_theme))
+            .child(AppTitleBar)
             .child(
                 div()
                     .h_flex()
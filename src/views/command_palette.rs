@@ -0,0 +1,162 @@
+use gpui::*;
+use gpui_component::list::{List, ListDelegate, ListItem, ListState};
+use gpui_component::{ActiveTheme, IndexPath, WindowExt, h_flex, v_flex};
+
+use crate::models::{SearchMatch, search};
+use crate::views::MainPage;
+
+actions!(command_palette, [ToggleCommandPalette]);
+
+/// Height of a palette row, the list requires all rows to have the same height
+const ITEM_HEIGHT: Pixels = px(44.);
+
+/// Search overlay opened with Ctrl+K / Cmd+K to jump to a component or example
+pub struct CommandPalette {
+    list: Entity<ListState<CommandPaletteDelegate>>,
+}
+
+/// The palette last opened, released by the dialog layer once it closes
+struct OpenPalette(WeakEntity<CommandPalette>);
+
+impl Global for OpenPalette {}
+
+impl CommandPalette {
+    pub fn new(main_page: Entity<MainPage>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let delegate = CommandPaletteDelegate {
+            main_page,
+            matches: search(""),
+            selected_index: Some(IndexPath::default()),
+        };
+        let list = cx.new(|cx| ListState::new(delegate, window, cx).searchable(true));
+
+        Self { list }
+    }
+
+    /// Open the palette in the dialog layer, or close it if it is already open
+    pub fn toggle(main_page: Entity<MainPage>, window: &mut Window, cx: &mut App) {
+        // Other dialogs stay open, the palette opens on top of them
+        if Self::is_open(cx) && window.has_active_dialog(cx) {
+            window.close_dialog(cx);
+            return;
+        }
+
+        let palette = cx.new(|cx| CommandPalette::new(main_page, window, cx));
+        cx.set_global(OpenPalette(palette.downgrade()));
+        let list = palette.read(cx).list.clone();
+        window.open_dialog(cx, move |dialog, _, _| {
+            dialog
+                .w(px(560.))
+                .margin_top(px(80.))
+                .close_button(false)
+                .child(palette.clone())
+        });
+        list.update(cx, |list, cx| list.focus(window, cx));
+    }
+
+    fn is_open(cx: &App) -> bool {
+        cx.try_global::<OpenPalette>()
+            .is_some_and(|palette| palette.0.upgrade().is_some())
+    }
+}
+
+impl Render for CommandPalette {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .h(px(420.))
+            .child(List::new(&self.list).search_placeholder("Search components and examples..."))
+    }
+}
+
+struct CommandPaletteDelegate {
+    main_page: Entity<MainPage>,
+    matches: Vec<SearchMatch>,
+    selected_index: Option<IndexPath>,
+}
+
+impl ListDelegate for CommandPaletteDelegate {
+    type Item = ListItem;
+
+    fn perform_search(
+        &mut self,
+        query: &str,
+        _window: &mut Window,
+        cx: &mut Context<ListState<Self>>,
+    ) -> Task<()> {
+        self.matches = search(query);
+        self.selected_index = (!self.matches.is_empty()).then(IndexPath::default);
+        cx.notify();
+        Task::ready(())
+    }
+
+    fn items_count(&self, _section: usize, _cx: &App) -> usize {
+        self.matches.len()
+    }
+
+    fn render_item(
+        &mut self,
+        ix: IndexPath,
+        _window: &mut Window,
+        cx: &mut Context<ListState<Self>>,
+    ) -> Option<Self::Item> {
+        let item = self.matches.get(ix.row)?;
+        let detail = match &item.example {
+            Some(_) => "Example".to_string(),
+            None => item.component.category().to_string(),
+        };
+
+        Some(
+            ListItem::new(ix)
+                .h(ITEM_HEIGHT)
+                .selected(self.selected_index == Some(ix))
+                .child(
+                    h_flex()
+                        .w_full()
+                        .justify_between()
+                        .gap_4()
+                        .child(div().truncate().child(item.title()))
+                        .child(
+                            div()
+                                .flex_shrink_0()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(detail),
+                        ),
+                ),
+        )
+    }
+
+    fn set_selected_index(
+        &mut self,
+        ix: Option<IndexPath>,
+        _window: &mut Window,
+        cx: &mut Context<ListState<Self>>,
+    ) {
+        self.selected_index = ix;
+        cx.notify();
+    }
+
+    fn confirm(
+        &mut self,
+        _secondary: bool,
+        window: &mut Window,
+        cx: &mut Context<ListState<Self>>,
+    ) {
+        let Some(item) = self
+            .selected_index
+            .and_then(|ix| self.matches.get(ix.row))
+            .cloned()
+        else {
+            return;
+        };
+
+        window.close_dialog(cx);
+        self.main_page.update(cx, |page, cx| match &item.example {
+            Some(example) => page.show_example(item.component, example.id, window, cx),
+            None => page.show_component(item.component, window, cx),
+        });
+    }
+
+    fn cancel(&mut self, window: &mut Window, cx: &mut Context<ListState<Self>>) {
+        window.close_dialog(cx);
+    }
+}
@@ -1,17 +1,54 @@
-use crate::models::Components;
-use gpui::*;
-use gpui_component::{divider::Divider, link::Link, scroll::ScrollableElement};
+use std::collections::HashMap;
+
+use crate::models::{Components, ExampleInfo, subtitle};
+use crate::preferences::Preferences;
+use gpui::{prelude::FluentBuilder, *};
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::input::{Input, InputEvent, InputState};
+use gpui_component::{
+    ActiveTheme, Icon, IconName, Sizable, clipboard::Clipboard, divider::Divider, h_flex,
+    link::Link, scroll::ScrollableElement, v_flex,
+};
+
+/// Line height of the code editor, used to size the "View code" panel
+const CODE_LINE_HEIGHT: Pixels = px(20.);
+const CODE_MAX_HEIGHT: Pixels = px(480.);
 
 pub struct ComponentShowcase {
     pub component: Components,
-    current_view: Option<AnyView>,
+    current_view: Option<AnyEntity>,
+    examples: Vec<ExampleInfo>,
+    scroll_handle: ScrollHandle,
+    search_input: Entity<InputState>,
+    /// Read-only code editors of the examples whose "View code" panel is open
+    source_editors: HashMap<&'static str, Entity<InputState>>,
+    /// Tab size the source editors were built with
+    tab_size: usize,
+    _subscriptions: Vec<Subscription>,
 }
 
 impl ComponentShowcase {
-    pub fn new(component: Components) -> Self {
+    pub fn new(component: Components, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let search_input =
+            cx.new(|cx| InputState::new(window, cx).placeholder("Search examples..."));
+        let _subscriptions = vec![
+            cx.subscribe(&search_input, |_, _, ev: &InputEvent, cx| {
+                if let InputEvent::Change = ev {
+                    cx.notify();
+                }
+            }),
+            cx.observe_global_in::<Preferences>(window, Self::apply_tab_size),
+        ];
+
         Self {
             component,
             current_view: None,
+            examples: component.entry().examples(),
+            scroll_handle: ScrollHandle::new(),
+            search_input,
+            source_editors: HashMap::new(),
+            tab_size: Preferences::global(cx).editor_tab_size,
+            _subscriptions,
         }
     }
 
@@ -22,27 +59,239 @@ impl ComponentShowcase {
         cx: &mut Context<Self>,
     ) {
         self.component = component;
+        self.examples = component.entry().examples();
         // Create new view for the component
-        self.current_view = Some(self.component.create_view(window, cx));
+        self.current_view = Some(self.component.entry().build(window, cx));
+        self.source_editors.clear();
+        self.scroll_handle.set_offset(point(px(0.), px(0.)));
+        cx.notify();
+    }
+
+    /// Scroll to the example with the given id, returns false if there is no such example
+    pub fn show_example(&mut self, id: &str, cx: &mut Context<Self>) -> bool {
+        let Some(ix) = self.examples.iter().position(|example| example.id == id) else {
+            return false;
+        };
+
+        self.scroll_handle.scroll_to_top_of_item(ix);
+        cx.notify();
+        true
+    }
+
+    /// Vertical scroll offset of the examples
+    pub fn scroll_offset(&self) -> Pixels {
+        self.scroll_handle.offset().y
+    }
+
+    pub fn set_scroll_offset(&mut self, offset: Pixels, cx: &mut Context<Self>) {
+        self.scroll_handle.set_offset(point(px(0.), offset));
+        cx.notify();
+    }
+
+    fn toggle_source(
+        &mut self,
+        example: &ExampleInfo,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.source_editors.remove(example.id).is_none()
+            && let Some(source) = example.source
+        {
+            let editor = Self::source_editor(source, window, cx);
+            self.source_editors.insert(example.id, editor);
+        }
+        cx.notify();
+    }
+
+    fn source_editor(
+        source: &'static str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Entity<InputState> {
+        cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor("rust")
+                .line_number(true)
+                .tab_size(Preferences::global(cx).tab_size())
+                .default_value(source)
+        })
+    }
+
+    /// Rebuild the open source editors when the tab size preference changes,
+    /// an editor only takes its tab size when it is built
+    fn apply_tab_size(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let tab_size = Preferences::global(cx).editor_tab_size;
+        if tab_size == self.tab_size {
+            return;
+        }
+        self.tab_size = tab_size;
+
+        for example in &self.examples {
+            if let Some(editor) = self.source_editors.get_mut(example.id)
+                && let Some(source) = example.source
+            {
+                *editor = Self::source_editor(source, window, cx);
+            }
+        }
         cx.notify();
     }
+
+    fn render_source(&self, example: &ExampleInfo, cx: &mut Context<Self>) -> Option<AnyElement> {
+        let source = example.source?;
+        let editor = self.source_editors.get(example.id);
+        let height = (CODE_LINE_HEIGHT * (source.lines().count() + 2) as f32).min(CODE_MAX_HEIGHT);
+
+        Some(
+            v_flex()
+                .w_full()
+                .max_w(px(720.))
+                .gap_2()
+                .child(
+                    h_flex()
+                        .gap_2()
+                        .child(
+                            Button::new(SharedString::from(format!("view-code-{}", example.id)))
+                                .ghost()
+                                .xsmall()
+                                .icon(if editor.is_some() {
+                                    IconName::ChevronUp
+                                } else {
+                                    IconName::ChevronDown
+                                })
+                                .label(if editor.is_some() {
+                                    "Hide code"
+                                } else {
+                                    "View code"
+                                })
+                                .on_click(cx.listener({
+                                    let example = example.clone();
+                                    move |this, _, window, cx| {
+                                        this.toggle_source(&example, window, cx);
+                                    }
+                                })),
+                        )
+                        .when(editor.is_some(), |this| {
+                            this.child(
+                                Clipboard::new(SharedString::from(format!(
+                                    "copy-code-{}",
+                                    example.id
+                                )))
+                                .value(source),
+                            )
+                        }),
+                )
+                .when_some(editor, |this, editor| {
+                    this.child(Input::new(editor).disabled(true).h(height))
+                })
+                .into_any_element(),
+        )
+    }
+
+    fn render_summary(&self, cx: &Context<Self>) -> impl IntoElement {
+        let not_implemented = self
+            .examples
+            .iter()
+            .filter(|example| !example.implemented)
+            .count();
+
+        h_flex()
+            .gap_2()
+            .text_sm()
+            .text_color(cx.theme().muted_foreground)
+            .child(format!("{} examples", self.examples.len()))
+            .when(not_implemented > 0, |this| {
+                this.child("·")
+                    .child(format!("{} not implemented yet", not_implemented))
+            })
+    }
+
+    fn render_table_of_contents(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let query = self.search_input.read(cx).value().to_lowercase();
+
+        v_flex()
+            .w(px(220.))
+            .flex_shrink_0()
+            .gap_2()
+            .child(
+                Input::new(&self.search_input)
+                    .small()
+                    .prefix(Icon::new(IconName::Search).small())
+                    .cleanable(true),
+            )
+            .child(
+                v_flex().flex_1().min_h_0().overflow_y_scrollbar().children(
+                    self.examples
+                        .iter()
+                        .filter(|example| {
+                            query.is_empty() || example.title.to_lowercase().contains(&query)
+                        })
+                        .map(|example| {
+                            let id = example.id;
+                            div()
+                                .id(SharedString::from(format!("toc-{}", id)))
+                                .px_2()
+                                .py_1()
+                                .rounded(cx.theme().radius)
+                                .text_sm()
+                                .cursor_pointer()
+                                .when(!example.implemented, |this| {
+                                    this.text_color(cx.theme().muted_foreground)
+                                })
+                                .hover(|this| this.bg(cx.theme().accent))
+                                .child(example.title)
+                                .on_click(cx.listener(move |this, _, _, cx| {
+                                    this.show_example(id, cx);
+                                }))
+                        }),
+                ),
+            )
+    }
 }
 
 impl Render for ComponentShowcase {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        let title = self.component.to_string();
-        let description = self.component.description();
-        let link = self.component.link();
+        let entry = self.component.entry();
+        let title = entry.name;
+        let description = entry.description;
+        let link = entry.link;
 
         // Create view if not exists
         let component_view = if let Some(view) = &self.current_view {
             view.clone()
         } else {
-            let view = self.component.create_view(window, cx);
+            let view = entry.build(window, cx);
             self.current_view = Some(view.clone());
             view
         };
 
+        let sections = self
+            .examples
+            .iter()
+            .zip(entry.render_examples(&component_view, window, cx))
+            .map(|(example, element)| {
+                v_flex()
+                    .gap_2()
+                    .pb_4()
+                    .w_full()
+                    .child(subtitle(example.title))
+                    .when_some(example.description, |this, description| {
+                        this.child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(description),
+                        )
+                    })
+                    .child(
+                        div()
+                            .w_full()
+                            .when(!example.full_width, |this| this.max_w_96())
+                            .child(element),
+                    )
+                    .children(self.render_source(example, cx))
+            })
+            .collect::<Vec<_>>();
+
         div()
             .flex()
             .flex_col()
@@ -65,15 +314,33 @@ impl Render for ComponentShowcase {
                             .text_base()
                             .text_color(rgb(0x666666))
                             .child(description),
-                    ),
+                    )
+                    .child(self.render_summary(cx)),
             )
             .child(Divider::horizontal())
             .child(
-                div()
+                h_flex()
                     .flex_1()
                     .min_h_0()
-                    .overflow_y_scrollbar()
-                    .child(component_view),
+                    .items_start()
+                    .gap_6()
+                    .child(
+                        div()
+                            .relative()
+                            .flex_1()
+                            .h_full()
+                            .child(
+                                v_flex()
+                                    .id("examples")
+                                    .size_full()
+                                    .gap_2()
+                                    .overflow_y_scroll()
+                                    .track_scroll(&self.scroll_handle)
+                                    .children(sections),
+                            )
+                            .vertical_scrollbar(&self.scroll_handle),
+                    )
+                    .child(self.render_table_of_contents(cx)),
             )
     }
 }
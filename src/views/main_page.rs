@@ -11,6 +11,11 @@ impl MainPage {
         Self { showcase: None }
     }
 
+    /// The showcase of the current component, `None` on the welcome page
+    pub fn showcase(&self) -> Option<&Entity<ComponentShowcase>> {
+        self.showcase.as_ref()
+    }
+
     pub fn show_component(
         &mut self,
         component: Components,
@@ -22,11 +27,27 @@ impl MainPage {
                 showcase.set_component(component, window, cx);
             });
         } else {
-            self.showcase = Some(cx.new(|_cx| ComponentShowcase::new(component)));
+            self.showcase = Some(cx.new(|cx| ComponentShowcase::new(component, window, cx)));
         }
         cx.notify();
     }
 
+    /// Show the component page and scroll to one of its examples
+    pub fn show_example(
+        &mut self,
+        component: Components,
+        example_id: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.show_component(component, window, cx);
+        if let Some(showcase) = &self.showcase {
+            showcase.update(cx, |showcase, cx| {
+                showcase.show_example(example_id, cx);
+            });
+        }
+    }
+
     pub fn show_welcome(&mut self, cx: &mut Context<Self>) {
         self.showcase = None;
         cx.notify();
@@ -1,9 +1,11 @@
 pub mod app;
+pub mod command_palette;
 pub mod component_showcase;
 pub mod main_page;
 pub mod title_bar;
 
 pub use app::*;
+pub use command_palette::*;
 pub use component_showcase::*;
 pub use main_page::*;
 pub use title_bar::*;
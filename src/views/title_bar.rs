@@ -1,23 +1,98 @@
 use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::menu::DropdownMenu;
 use gpui_component::*;
+use serde::Deserialize;
+use strum::IntoEnumIterator;
+
+use crate::preferences::{Preferences, ThemePreference};
+use crate::themes::ThemeLibrary;
+use crate::views::ToggleCommandPalette;
+
+/// Switch the theme mode and remember the choice
+#[derive(Action, Clone, PartialEq, Deserialize)]
+#[action(namespace = title_bar, no_json)]
+pub struct SwitchTheme(pub ThemePreference);
+
+/// Use a theme by name for the light or dark mode, depending on the theme
+#[derive(Action, Clone, PartialEq, Deserialize)]
+#[action(namespace = title_bar, no_json)]
+pub struct SelectTheme(pub SharedString);
 
 #[derive(IntoElement)]
 pub struct AppTitleBar;
 
-impl AppTitleBar {
-    pub fn new() -> Self {
-        Self
-    }
-}
-
 impl RenderOnce for AppTitleBar {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let preference = Preferences::global(cx).theme_mode();
+        let active_theme = cx.theme().theme_name().clone();
+        let theme_icon = if cx.theme().is_dark() {
+            IconName::Moon
+        } else {
+            IconName::Sun
+        };
+
         TitleBar::new().child(
             h_flex()
                 .w_full()
-                .justify_center()
-                .pr_20()
-                .child("GPUI Component Sample"),
+                .pr_2()
+                .child(div().flex_1())
+                .child("GPUI Component Sample")
+                .child(
+                    h_flex()
+                        .flex_1()
+                        .justify_end()
+                        .gap_1()
+                        .child(
+                            Button::new("command-palette")
+                                .ghost()
+                                .small()
+                                .icon(IconName::Search)
+                                .tooltip_with_action(
+                                    "Search components",
+                                    &ToggleCommandPalette,
+                                    None,
+                                )
+                                .on_click(|_, window, cx| {
+                                    window.dispatch_action(Box::new(ToggleCommandPalette), cx);
+                                }),
+                        )
+                        .child(
+                            Button::new("theme-mode")
+                                .ghost()
+                                .small()
+                                .icon(theme_icon)
+                                .tooltip("Theme")
+                                .dropdown_menu_with_anchor(Corner::TopRight, move |menu, _, _| {
+                                    ThemePreference::iter().fold(menu, |menu, mode| {
+                                        menu.menu_with_check(
+                                            mode.to_string(),
+                                            mode == preference,
+                                            Box::new(SwitchTheme(mode)),
+                                        )
+                                    })
+                                }),
+                        )
+                        .child(
+                            Button::new("theme-picker")
+                                .ghost()
+                                .small()
+                                .icon(IconName::Palette)
+                                .tooltip("Color theme")
+                                .dropdown_menu_with_anchor(Corner::TopRight, move |menu, _, cx| {
+                                    ThemeLibrary::themes(cx).into_iter().fold(
+                                        menu.scrollable(true).max_h(px(400.)),
+                                        |menu, theme| {
+                                            menu.menu_with_check(
+                                                theme.name.clone(),
+                                                theme.name == active_theme,
+                                                Box::new(SelectTheme(theme.name.clone())),
+                                            )
+                                        },
+                                    )
+                                }),
+                        ),
+                ),
         )
     }
 }
@@ -0,0 +1,245 @@
+//! Headless smoke tests rendering every showcase page, to catch panics in the
+//! examples, e.g. a date or color that fails to parse after a dependency bump,
+//! and clicking the triggers of the overlays to check that they open.
+
+use std::time::Duration;
+
+use gpui::{
+    AnyEntity, AppContext as _, Context, Entity, InteractiveElement as _, IntoElement, Modifiers,
+    ParentElement as _, Render, Styled as _, TestAppContext, VisualTestContext, Window, div, point,
+    px,
+};
+use gpui_component::{Root, WindowExt as _};
+use gpui_component_sample::models::Components;
+use gpui_component_sample::preferences::Preferences;
+use gpui_component_sample::session::Session;
+use gpui_component_sample::themes::ThemeLibrary;
+use gpui_component_sample::views::{MyApp, ToggleCommandPalette};
+use strum::IntoEnumIterator;
+
+fn init(cx: &mut TestAppContext) {
+    cx.update(|cx| {
+        gpui_component::init(cx);
+        gpui_component_sample::init_languages();
+        gpui_component_sample::models::init(cx);
+        // In-memory defaults, so the files of the user running the tests are
+        // neither read nor written
+        cx.set_global(Preferences::default());
+        cx.set_global(Session::default());
+        cx.set_global(ThemeLibrary::default());
+    });
+}
+
+/// Open the whole app in a window, like `main.rs` does
+fn open_app(cx: &mut TestAppContext) -> (Entity<MyApp>, &mut VisualTestContext) {
+    let mut app = None;
+    let (_, cx) = cx.add_window_view(|window, cx| {
+        let view = cx.new(|cx| MyApp::new(window, cx));
+        app = Some(view.clone());
+        Root::new(view, window, cx)
+    });
+    (app.unwrap(), cx)
+}
+
+/// Draw a frame and run the work it started
+fn draw(cx: &mut VisualTestContext) {
+    cx.update(|window, _| window.refresh());
+    cx.run_until_parked();
+}
+
+/// Renders the examples of the views built by `create_view`, which needs
+/// the context of a view
+struct Host {
+    views: Vec<(Components, AnyEntity)>,
+}
+
+impl Render for Host {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        div().children(
+            self.views
+                .iter()
+                .flat_map(|(component, view)| component.entry().render_examples(view, window, cx)),
+        )
+    }
+}
+
+/// Renders a single example at the top left of the window, above the dialog,
+/// sheet and notification layers like `MyApp`
+struct ExampleHost {
+    component: Components,
+    view: AnyEntity,
+    example: usize,
+}
+
+impl Render for ExampleHost {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let example = self
+            .component
+            .entry()
+            .render_examples(&self.view, window, cx)
+            .into_iter()
+            .nth(self.example);
+
+        div()
+            .size_full()
+            .child(div().debug_selector(|| "example".into()).children(example))
+            .children(Root::render_dialog_layer(window, cx))
+            .children(Root::render_sheet_layer(window, cx))
+            .children(Root::render_notification_layer(window, cx))
+    }
+}
+
+/// Open an example whose trigger comes first, and click the trigger
+fn click_trigger<'a>(
+    cx: &'a mut TestAppContext,
+    component: Components,
+    id: &str,
+) -> &'a mut VisualTestContext {
+    init(cx);
+    let example = component
+        .entry()
+        .examples()
+        .iter()
+        .position(|example| example.id == id)
+        .unwrap();
+    let (_, cx) = cx.add_window_view(|window, cx| {
+        let view = component.create_view(window, cx);
+        let host = cx.new(|_| ExampleHost {
+            component,
+            view,
+            example,
+        });
+        Root::new(host, window, cx)
+    });
+    draw(cx);
+
+    let bounds = cx.debug_bounds("example").unwrap();
+    cx.simulate_click(bounds.origin + point(px(8.), px(8.)), Modifiers::none());
+    draw(cx);
+    cx
+}
+
+/// Whether the focused element is inside an element with the key context,
+/// popovers and menus focus their content when they open
+fn focus_is_in(context: &str, cx: &mut VisualTestContext) -> bool {
+    cx.update(|window, _| {
+        window
+            .context_stack()
+            .iter()
+            .any(|key_context| key_context.contains(context))
+    })
+}
+
+#[gpui::test]
+fn every_component_creates_its_view(cx: &mut TestAppContext) {
+    init(cx);
+    let (host, cx) = cx.add_window_view(|_, _| Host { views: Vec::new() });
+
+    for component in Components::iter() {
+        host.update_in(cx, |host, window, cx| {
+            let view = component.create_view(window, cx);
+            host.views.push((component, view));
+            cx.notify();
+        });
+        draw(cx);
+    }
+}
+
+#[gpui::test]
+fn every_showcase_page_renders(cx: &mut TestAppContext) {
+    init(cx);
+    let (app, cx) = open_app(cx);
+    let main_page = app.read_with(cx, |app, _| app.main_page.clone());
+
+    for component in Components::iter() {
+        main_page.update_in(cx, |page, window, cx| {
+            page.show_component(component, window, cx);
+        });
+        draw(cx);
+    }
+
+    main_page.update(cx, |page, cx| page.show_welcome(cx));
+    draw(cx);
+}
+
+#[gpui::test]
+fn command_palette_opens_a_component(cx: &mut TestAppContext) {
+    init(cx);
+    let (app, cx) = open_app(cx);
+    draw(cx);
+
+    cx.dispatch_action(ToggleCommandPalette);
+    draw(cx);
+    assert!(cx.update(|window, cx| window.has_active_dialog(cx)));
+
+    cx.simulate_input("date picker");
+    cx.executor().advance_clock(Duration::from_secs(1));
+    draw(cx);
+    cx.simulate_keystrokes("enter");
+    draw(cx);
+
+    assert!(!cx.update(|window, cx| window.has_active_dialog(cx)));
+    let component = app.read_with(cx, |app, cx| {
+        app.main_page
+            .read(cx)
+            .showcase()
+            .map(|showcase| showcase.read(cx).component)
+    });
+    assert_eq!(component, Some(Components::DatePicker));
+}
+
+#[gpui::test]
+fn command_palette_leaves_other_dialogs_open(cx: &mut TestAppContext) {
+    init(cx);
+    let (_, cx) = open_app(cx);
+    draw(cx);
+
+    cx.update(|window, cx| window.open_dialog(cx, |dialog, _, _| dialog.title("Settings")));
+    draw(cx);
+
+    // Ctrl+K twice opens then closes the palette, not the dialog below it
+    cx.dispatch_action(ToggleCommandPalette);
+    draw(cx);
+    cx.dispatch_action(ToggleCommandPalette);
+    draw(cx);
+    assert!(cx.update(|window, cx| window.has_active_dialog(cx)));
+}
+
+#[gpui::test]
+fn dialog_trigger_opens_a_dialog(cx: &mut TestAppContext) {
+    let cx = click_trigger(cx, Components::Dialog, "basic_dialog");
+    assert!(cx.update(|window, cx| window.has_active_dialog(cx)));
+}
+
+#[gpui::test]
+fn sheet_trigger_opens_a_sheet(cx: &mut TestAppContext) {
+    let cx = click_trigger(cx, Components::Sheet, "sheet_steps");
+    assert!(cx.update(|window, cx| window.has_active_sheet(cx)));
+}
+
+#[gpui::test]
+fn popover_trigger_opens_a_popover(cx: &mut TestAppContext) {
+    let cx = click_trigger(cx, Components::Popover, "basic_popover");
+    assert!(focus_is_in("Popover", cx));
+}
+
+#[gpui::test]
+fn dropdown_trigger_opens_a_menu(cx: &mut TestAppContext) {
+    let cx = click_trigger(cx, Components::Menu, "dropdown_menu");
+    assert!(focus_is_in("PopupMenu", cx));
+}
+
+#[gpui::test]
+fn upload_notification_cancel_dismisses_it(cx: &mut TestAppContext) {
+    let cx = click_trigger(cx, Components::Notification, "dismiss_by_id");
+    assert_eq!(cx.update(|window, cx| window.notifications(cx).len()), 1);
+
+    let bounds = cx.debug_bounds("cancel-upload-notification").unwrap();
+    cx.simulate_click(bounds.center(), Modifiers::none());
+    draw(cx);
+
+    // The notification waits for its closing animation, on a real timer
+    std::thread::sleep(Duration::from_millis(300));
+    draw(cx);
+    assert!(cx.update(|window, cx| window.notifications(cx).is_empty()));
+}